@@ -0,0 +1,220 @@
+use crate::{AppResult, AppErrorType, Config};
+use crate::bitbucket::models::*;
+use crate::code_host::{ChangeRequest, CodeHost};
+use crate::error::AppErrorExt;
+use async_trait::async_trait;
+use base64::Engine;
+use reqwest::{Client, Response, StatusCode, header::{AUTHORIZATION, HeaderMap, HeaderValue, USER_AGENT}};
+
+const BITBUCKET_API_URL: &str = "https://api.bitbucket.org/2.0";
+const BITBUCKET_WEB_URL: &str = "https://bitbucket.org";
+
+// BBQL 문자열 값은 큰따옴표로 감싸므로 따옴표와 역슬래시를 이스케이프합니다
+fn quote_query_value(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+pub struct BitbucketClient {
+    client: Client,
+    repo_url: String,
+    repo_api_url: String,
+}
+
+impl BitbucketClient {
+    pub fn new(config: &Config) -> AppResult<Self> {
+        // 사용자명이 있으면 앱 비밀번호(Basic), 없으면 액세스 토큰(Bearer)으로 인증합니다
+        let auth = match config.bitbucket_username {
+            Some(ref username) => {
+                let credentials = format!("{}:{}", username, config.bitbucket_token);
+                format!("Basic {}", base64::engine::general_purpose::STANDARD.encode(credentials))
+            }
+            None => format!("Bearer {}", config.bitbucket_token),
+        };
+
+        let mut headers = HeaderMap::new();
+        headers.insert(AUTHORIZATION, HeaderValue::from_str(&auth)
+            .with_app_type(AppErrorType::BitbucketAuthenticationError)?);
+        headers.insert(USER_AGENT, HeaderValue::from_static("jgf-cli"));
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .with_app_type(AppErrorType::BitbucketConnectionError)?;
+
+        let repo_path = format!("{}/{}", config.bitbucket_workspace, config.bitbucket_repo);
+
+        Ok(BitbucketClient {
+            client,
+            repo_url: format!("{}/{}", BITBUCKET_WEB_URL, repo_path),
+            repo_api_url: format!("{}/repositories/{}", BITBUCKET_API_URL, repo_path),
+        })
+    }
+
+    async fn handle_response<T: for<'de> serde::Deserialize<'de>>(
+        &self,
+        response: Response,
+        context: &str,
+    ) -> AppResult<T> {
+        let status = response.status();
+
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(AppErrorType::BitbucketAuthenticationError.into());
+        }
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppErrorType::BitbucketApiError(
+                format!("{} ({}): {}", context, status, error_text)
+            ).into());
+        }
+
+        response.json().await
+            .with_app_type(AppErrorType::BitbucketApiError(format!("{}: 응답 파싱 실패", context)))
+    }
+
+    pub async fn create_pull_request(
+        &self,
+        title: &str,
+        description: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> AppResult<PullRequest> {
+        // 같은 브랜치의 열린 PR이 있으면 GitHub/GitLab처럼 "already exists"로 알립니다
+        if let Some(pr) = self.find_pull_request_by_branch(source_branch).await?
+            && pr.state == "OPEN" {
+            return Err(AppErrorType::BitbucketApiError(
+                format!("PR #{} already exists for {}", pr.id, source_branch)
+            ).into());
+        }
+
+        let url = format!("{}/pullrequests", self.repo_api_url);
+
+        let request = CreatePullRequestRequest {
+            title: title.to_string(),
+            description: description.to_string(),
+            source: BranchRef::new(source_branch),
+            destination: BranchRef::new(target_branch),
+        };
+
+        let response = self.client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .with_app_type(AppErrorType::BitbucketConnectionError)?;
+
+        self.handle_response(response, "PR 생성 실패").await
+    }
+
+    pub async fn get_pull_request(&self, id: u64) -> AppResult<PullRequest> {
+        let url = format!("{}/pullrequests/{}", self.repo_api_url, id);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::BitbucketConnectionError)?;
+
+        self.handle_response(response, "PR 조회 실패").await
+    }
+
+    pub async fn find_pull_request_by_branch(&self, source_branch: &str) -> AppResult<Option<PullRequest>> {
+        let url = format!("{}/pullrequests", self.repo_api_url);
+        let query = format!("source.branch.name = {}", quote_query_value(source_branch));
+
+        // state를 지정하지 않으면 열린 PR만 조회됩니다
+        let response = self.client
+            .get(&url)
+            .query(&[
+                ("q", query.as_str()),
+                ("state", "OPEN"),
+                ("state", "MERGED"),
+                ("state", "DECLINED"),
+                ("state", "SUPERSEDED"),
+                ("sort", "-created_on"),
+                ("pagelen", "1"),
+            ])
+            .send()
+            .await
+            .with_app_type(AppErrorType::BitbucketConnectionError)?;
+
+        let page: Page<PullRequest> = self.handle_response(response, "PR 목록 조회 실패").await?;
+
+        Ok(page.values.into_iter().next())
+    }
+
+    pub async fn merge_pull_request(&self, id: u64) -> AppResult<()> {
+        let url = format!("{}/pullrequests/{}/merge", self.repo_api_url, id);
+
+        let response = self.client
+            .post(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::BitbucketConnectionError)?;
+
+        // 오래 걸리는 머지는 202와 함께 작업 상태가 반환되므로 응답 형태는 확인하지 않습니다
+        let _: serde_json::Value = self.handle_response(response, "PR 머지 실패").await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl CodeHost for BitbucketClient {
+    fn name(&self) -> &'static str {
+        "Bitbucket"
+    }
+
+    fn change_request_label(&self) -> &'static str {
+        "PR"
+    }
+
+    fn change_request_name(&self) -> &'static str {
+        "Pull Request"
+    }
+
+    fn icon_url(&self) -> String {
+        format!("{}/favicon.ico", BITBUCKET_WEB_URL)
+    }
+
+    fn branch_search_url(&self, branch: &str) -> String {
+        format!("{}/pull-requests?state=ALL&at=refs/heads/{}", self.repo_url, branch)
+    }
+
+    async fn create_change_request(
+        &self,
+        title: &str,
+        body: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> AppResult<ChangeRequest> {
+        let pr = self.create_pull_request(title, body, source_branch, target_branch).await?;
+        Ok(pr.into())
+    }
+
+    async fn find_change_request(&self, source_branch: &str) -> AppResult<Option<ChangeRequest>> {
+        let pr = self.find_pull_request_by_branch(source_branch).await?;
+        Ok(pr.map(ChangeRequest::from))
+    }
+
+    async fn get_change_request(&self, number: u64) -> AppResult<ChangeRequest> {
+        let pr = self.get_pull_request(number).await?;
+        Ok(pr.into())
+    }
+
+    async fn merge_change_request(&self, number: u64) -> AppResult<()> {
+        self.merge_pull_request(number).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_bbql_values() {
+        assert_eq!(quote_query_value("feature/PROJ-1"), r#""feature/PROJ-1""#);
+        assert_eq!(quote_query_value(r#"a"b"#), r#""a\"b""#);
+        assert_eq!(quote_query_value(r"a\b"), r#""a\\b""#);
+        assert_eq!(quote_query_value(r#"x" OR state = "OPEN"#), r#""x\" OR state = \"OPEN""#);
+    }
+}
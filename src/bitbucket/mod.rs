@@ -0,0 +1,5 @@
+pub mod client;
+pub mod models;
+
+pub use client::BitbucketClient;
+pub use models::*;
\ No newline at end of file
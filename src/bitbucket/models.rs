@@ -0,0 +1,124 @@
+use crate::code_host::{ChangeRequest, ChangeRequestState};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BranchRef {
+    pub branch: BranchName,
+    #[serde(default, skip_serializing)]
+    pub commit: Option<CommitRef>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct CommitRef {
+    pub hash: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BranchName {
+    pub name: String,
+}
+
+impl BranchRef {
+    pub fn new(name: &str) -> Self {
+        BranchRef { branch: BranchName { name: name.to_string() }, commit: None }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct CreatePullRequestRequest {
+    pub title: String,
+    pub description: String,
+    pub source: BranchRef,
+    pub destination: BranchRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequest {
+    pub id: u64,
+    pub title: String,
+    pub description: Option<String>,
+    // OPEN, MERGED, DECLINED, SUPERSEDED
+    pub state: String,
+    pub links: PullRequestLinks,
+    pub source: BranchRef,
+    pub destination: BranchRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PullRequestLinks {
+    pub html: Link,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Link {
+    pub href: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Page<T> {
+    pub values: Vec<T>,
+    pub next: Option<String>,
+}
+
+impl From<PullRequest> for ChangeRequest {
+    fn from(pr: PullRequest) -> Self {
+        let state = match pr.state.as_str() {
+            "MERGED" => ChangeRequestState::Merged,
+            "OPEN" => ChangeRequestState::Open,
+            _ => ChangeRequestState::Closed,
+        };
+
+        ChangeRequest {
+            number: pr.id,
+            reference: format!("#{}", pr.id),
+            title: pr.title,
+            url: pr.links.html.href,
+            source_branch: pr.source.branch.name,
+            target_branch: pr.destination.branch.name,
+            state,
+            head_sha: pr.source.commit.map(|commit| commit.hash),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn pull_request(state: &str) -> PullRequest {
+        serde_json::from_value(json!({
+            "id": 7,
+            "title": "로그인 수정",
+            "description": null,
+            "state": state,
+            "links": { "html": { "href": "https://bitbucket.org/team/repo/pull-requests/7" } },
+            "source": { "branch": { "name": "feature/PROJ-1" }, "commit": { "hash": "1a2b3c4d5e6f" } },
+            "destination": { "branch": { "name": "main" } }
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn maps_pull_request_states() {
+        let state = |state: &str| ChangeRequest::from(pull_request(state)).state;
+
+        assert_eq!(state("OPEN"), ChangeRequestState::Open);
+        assert_eq!(state("MERGED"), ChangeRequestState::Merged);
+        assert_eq!(state("DECLINED"), ChangeRequestState::Closed);
+        assert_eq!(state("SUPERSEDED"), ChangeRequestState::Closed);
+    }
+
+    #[test]
+    fn maps_pull_request_fields() {
+        let change_request = ChangeRequest::from(pull_request("MERGED"));
+
+        assert_eq!(change_request.number, 7);
+        assert_eq!(change_request.reference, "#7");
+        assert_eq!(change_request.url, "https://bitbucket.org/team/repo/pull-requests/7");
+        assert_eq!(change_request.source_branch, "feature/PROJ-1");
+        assert_eq!(change_request.target_branch, "main");
+        assert!(change_request.contains_commit("1a2b3c4d5e6f7890abcdef1234567890abcdef12"));
+        assert!(!change_request.contains_commit("ffffffffffff7890abcdef1234567890abcdef12"));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::{AppError, AppResult, AppContext, Config, utils};
+use crate::jira::{IssueType, NewIssue};
+use clap::Args;
+
+#[derive(Args, Debug, Default)]
+pub struct CreateArgs {
+    #[arg(short = 't', long = "type", help = "이슈 타입 (예: Bug, Task, Story)")]
+    pub issue_type: Option<String>,
+    
+    #[arg(short, long, help = "티켓 제목")]
+    pub summary: Option<String>,
+    
+    #[arg(short, long, help = "티켓 설명")]
+    pub description: Option<String>,
+    
+    #[arg(short, long, help = "우선순위 (예: High, Medium)")]
+    pub priority: Option<String>,
+    
+    #[arg(long, help = "상위 티켓 번호 (하위 작업 생성 시 필수)")]
+    pub parent: Option<String>,
+    
+    #[arg(long, help = "생성 후 바로 작업 시작 (브랜치 생성)")]
+    pub start: bool,
+    
+    #[arg(long, help = "인터랙티브 모드 (기본값: --summary가 없으면 true)")]
+    pub interactive: Option<bool>,
+}
+
+fn issue_type_label(issue_type: &IssueType) -> String {
+    if issue_type.subtask {
+        format!("{} (하위 작업)", issue_type.name)
+    } else {
+        issue_type.name.clone()
+    }
+}
+
+fn optional(value: String) -> Option<String> {
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+pub async fn run(args: CreateArgs) -> AppResult<()> {
+    create_issue(args, false).await
+}
+
+// subtasks_only가 true이면 하위 작업 타입만 선택할 수 있습니다 (jgf subtask add)
+pub async fn create_issue(args: CreateArgs, subtasks_only: bool) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let jira_client = context.jira_client()?;
+    let project_key = context.config().jira_project.clone();
+    let interactive = args.interactive.unwrap_or(args.summary.is_none());
+    
+    let spinner = utils::create_spinner(&format!("프로젝트 {}의 이슈 타입을 조회하고 있습니다...", project_key));
+    let issue_types = jira_client.get_create_issue_types(&project_key).await;
+    spinner.finish_and_clear();
+    let issue_types: Vec<IssueType> = issue_types?
+        .into_iter()
+        .filter(|issue_type| !subtasks_only || issue_type.subtask)
+        .collect();
+    
+    if issue_types.is_empty() {
+        return Err(AppError::not_found(format!("프로젝트 {}에서 생성 가능한 이슈 타입이 없습니다", project_key)));
+    }
+    
+    let issue_type = match args.issue_type {
+        Some(ref name) => issue_types
+            .iter()
+            .find(|issue_type| issue_type.name.eq_ignore_ascii_case(name))
+            .cloned()
+            .ok_or_else(|| AppError::validation_error(format!(
+                "이슈 타입 '{}'를 찾을 수 없습니다. 사용 가능: {}",
+                name,
+                issue_types.iter().map(|issue_type| issue_type.name.as_str()).collect::<Vec<_>>().join(", ")
+            )))?,
+        None if issue_types.len() == 1 => issue_types[0].clone(),
+        None if interactive => {
+            let options: Vec<String> = issue_types.iter().map(issue_type_label).collect();
+            let selected = utils::prompt_select("이슈 타입을 선택하세요:", options)?;
+            issue_types
+                .iter()
+                .find(|issue_type| issue_type_label(issue_type) == selected)
+                .cloned()
+                .unwrap()
+        }
+        None => return Err(AppError::validation_error("--type 옵션으로 이슈 타입을 지정해주세요")),
+    };
+    
+    let summary = match args.summary {
+        Some(summary) => summary,
+        None => utils::prompt_text("티켓 제목:", None)?,
+    };
+    let summary = optional(summary)
+        .ok_or_else(|| AppError::validation_error("티켓 제목을 입력해주세요"))?;
+    
+    let description = match args.description {
+        Some(description) => optional(description),
+        None if interactive => optional(utils::prompt_text("티켓 설명 (생략 가능):", Some(""))?),
+        None => None,
+    };
+    
+    let priority = match args.priority {
+        Some(priority) => Some(priority),
+        None if interactive => {
+            let spinner = utils::create_spinner("우선순위 목록을 조회하고 있습니다...");
+            let priorities = jira_client.get_priorities().await;
+            spinner.finish_and_clear();
+            
+            match priorities {
+                Ok(priorities) if !priorities.is_empty() => {
+                    let mut options = vec!["기본값".to_string()];
+                    options.extend(priorities.into_iter().map(|priority| priority.name));
+                    let selected = utils::prompt_select("우선순위를 선택하세요:", options)?;
+                    (selected != "기본값").then_some(selected)
+                }
+                Ok(_) => None,
+                Err(e) => {
+                    utils::warning_message(&format!("우선순위 조회 실패: {}", e));
+                    None
+                }
+            }
+        }
+        None => None,
+    };
+    
+    let parent = match args.parent {
+        Some(parent) => optional(parent),
+        None if interactive && issue_type.subtask => optional(utils::prompt_text("상위 티켓 번호:", None)?),
+        None if interactive => optional(utils::prompt_text("상위 티켓 번호 (생략 가능):", Some(""))?),
+        None => None,
+    }
+    .map(|parent| utils::format_ticket_key(context.config(), &parent));
+    
+    if issue_type.subtask && parent.is_none() {
+        return Err(AppError::validation_error(format!("'{}' 타입은 상위 티켓 번호가 필요합니다 (--parent)", issue_type.name)));
+    }
+    
+    let new_issue = NewIssue {
+        issue_type_id: issue_type.id.clone(),
+        summary,
+        description,
+        priority,
+        parent,
+    };
+    
+    let spinner = utils::create_spinner("Jira 티켓을 생성하고 있습니다...");
+    let created = jira_client.create_issue(&project_key, &new_issue).await;
+    spinner.finish_and_clear();
+    let created = created?;
+    
+    utils::success_message(&format!("티켓 {}가 생성되었습니다: [{}] {}", created.key, issue_type.name, new_issue.summary));
+    utils::info_message(&format!("티켓 링크: {}", context.config().get_ticket_url(&created.key)));
+    
+    let should_start = args.start
+        || (interactive && utils::prompt_confirmation("생성한 티켓으로 바로 작업을 시작하시겠습니까?")?);
+    
+    if should_start {
+        println!();
+        crate::cli::commands::start::run(created.key, false).await?;
+    }
+    
+    Ok(())
+}
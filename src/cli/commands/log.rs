@@ -0,0 +1,50 @@
+use crate::{AppError, AppResult, AppContext, Config, git::GitOps, utils};
+use crate::cli::worklog;
+use crate::jira::worklog::{format_duration, parse_duration};
+use crate::timer::TimerStore;
+use chrono::{Duration, Utc};
+
+pub async fn run(duration: Option<String>, comment: Option<String>, ticket: Option<String>) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let ticket = match ticket {
+        Some(ticket) => utils::format_ticket_key(&config, ticket.trim()),
+        None => GitOps::open()?
+            .get_current_ticket_key(&config.ticket_key_parser()?)?
+            .ok_or_else(|| AppError::validation_error("현재 브랜치에서 티켓 번호를 찾을 수 없습니다. --ticket으로 티켓을 지정해주세요"))?,
+    };
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let store = TimerStore::new(context.config());
+    
+    let (seconds, timer) = match duration {
+        Some(ref duration) => {
+            let seconds = parse_duration(duration)
+                .ok_or_else(|| AppError::validation_error(format!("작업 시간 형식이 올바르지 않습니다: {} (예: 1h 30m, 2d, 45m / 최대 1w)", duration)))?;
+            (seconds, None)
+        }
+        None => match store.get(&ticket)? {
+            Some(timer) => {
+                let elapsed = format_duration(timer.elapsed_seconds());
+                utils::info_message(&format!("⏱ 타이머 경과 시간: {}", elapsed));
+                (worklog::prompt_duration(Some(&elapsed))?, Some(timer))
+            }
+            None => (worklog::prompt_duration(None)?, None),
+        },
+    };
+    
+    let started = match timer {
+        Some(ref timer) => timer.started_at,
+        None => Utc::now() - Duration::seconds(seconds as i64),
+    };
+    
+    worklog::log_work(&context, &ticket, seconds, started, comment.as_deref()).await?;
+    
+    if timer.is_some() {
+        store.start(&ticket)?;
+        utils::info_message("⏱ 타이머를 다시 시작합니다");
+    }
+    
+    Ok(())
+}
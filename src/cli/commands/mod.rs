@@ -2,4 +2,11 @@ pub mod init;
 pub mod tickets;
 pub mod start;
 pub mod pr;
-pub mod sync;
\ No newline at end of file
+pub mod sync;
+pub mod create;
+pub mod subtask;
+pub mod show;
+pub mod transition;
+pub mod log;
+pub mod release_notes;
+pub mod version;
\ No newline at end of file
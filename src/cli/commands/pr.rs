@@ -1,4 +1,8 @@
 use crate::{AppResult, AppContext, Config, git::GitOps, utils};
+use crate::cli;
+use crate::cli::worklog::{self, TimerFinish};
+use crate::code_host::{ChangeRequest, CodeHost};
+use crate::jira::{adf, RemoteLinkApplication, RemoteLinkIcon, RemoteLinkObject, RemoteLinkRequest, TransitionTarget, WorkflowStage};
 
 fn get_pr_template(config: &Config) -> Option<String> {
     if let Some(ref content) = config.pr_template_content {
@@ -8,6 +12,102 @@ fn get_pr_template(config: &Config) -> Option<String> {
     config.get_pr_template()
 }
 
+fn pull_request_comment(host: &dyn CodeHost, pr: &ChangeRequest) -> String {
+    format!(
+        "🔗 {} {} [{} {}]({}) ({} → {})",
+        host.name(),
+        host.change_request_label(),
+        pr.reference,
+        adf::escape_markdown(&pr.title),
+        pr.url,
+        adf::escape_markdown(&pr.source_branch),
+        adf::escape_markdown(&pr.target_branch),
+    )
+}
+
+// PR URL을 기준으로 코멘트와 원격 링크를 갱신하므로 여러 번 실행해도 중복되지 않습니다
+async fn link_pull_request(context: &AppContext, ticket_key: &str, pr: &ChangeRequest) {
+    let (Ok(tracker), Ok(host)) = (context.issue_tracker(), context.code_host()) else {
+        return;
+    };
+    let label = host.change_request_label();
+    
+    let spinner = utils::create_spinner(&format!("{} 티켓 {}에 {} 링크 등록 중...", tracker.name(), ticket_key, label));
+    
+    let link = RemoteLinkRequest {
+        global_id: pr.url.clone(),
+        application: RemoteLinkApplication {
+            app_type: format!("com.{}", host.name().to_lowercase()),
+            name: host.name().to_string(),
+        },
+        relationship: host.change_request_name().to_string(),
+        object: RemoteLinkObject {
+            url: pr.url.clone(),
+            title: format!("{} {}: {}", label, pr.reference, pr.title),
+            summary: Some(format!("{} → {}", pr.source_branch, pr.target_branch)),
+            icon: Some(RemoteLinkIcon {
+                url: host.icon_url(),
+                title: host.name().to_string(),
+            }),
+        },
+    };
+    
+    // 원격 링크는 Jira에만 있으므로 다른 트래커에는 코멘트만 남깁니다
+    let link_result = match context.jira_client() {
+        Ok(jira_client) => jira_client.upsert_remote_link(ticket_key, &link).await.map(|_| ()),
+        Err(_) => Ok(()),
+    };
+    let comment_result = tracker
+        .upsert_comment(ticket_key, &pr.url, &pull_request_comment(host, pr))
+        .await;
+    spinner.finish_and_clear();
+    
+    match (link_result, comment_result) {
+        (Ok(_), Ok(_)) => utils::link_message(&format!("{} 티켓 {}에 {} {} 링크와 코멘트를 등록했습니다", tracker.name(), ticket_key, label, pr.reference)),
+        (Err(e), _) | (_, Err(e)) => utils::warning_message(&format!("{}에 {} 정보 등록 실패: {}", tracker.name(), label, e)),
+    }
+}
+
+async fn update_ticket_status(context: &AppContext, ticket_key: &str) -> AppResult<()> {
+    let Ok(issue) = context.issue_tracker()?.get_issue(ticket_key).await else {
+        return Ok(());
+    };
+    
+    let workflow = &context.config().workflow;
+    let status = &issue.fields.status;
+    utils::info_message(&format!("현재 티켓 상태: {}", status.name));
+    
+    if workflow.is_in_stage(status, WorkflowStage::Done) {
+        utils::info_message(&format!("티켓이 이미 '{}' 상태입니다.", status.name));
+        return Ok(());
+    }
+    
+    // 리뷰 상태 매핑이 없으면 머지 후 sync에서 Done으로만 변경
+    if workflow.names(WorkflowStage::Review).is_empty() || workflow.is_in_stage(status, WorkflowStage::Review) {
+        utils::info_message(&format!("PR Merge 후 jgf sync 실행 시 '{}'으로 변경됩니다.", workflow.label(WorkflowStage::Done)));
+        return Ok(());
+    }
+    
+    let review_label = workflow.label(WorkflowStage::Review);
+    let should_update = utils::prompt_confirmation(&format!("티켓 상태를 '{}'로 변경하시겠습니까?", review_label))?;
+    
+    if should_update {
+        match cli::workflow::move_issue(context, ticket_key, TransitionTarget::Stage(WorkflowStage::Review)).await {
+            Ok(true) => {
+                utils::success_message(&format!("티켓 상태가 '{}'로 변경되었습니다", review_label));
+            }
+            Ok(false) => {
+                utils::info_message("상태 변경을 취소했습니다");
+            }
+            Err(e) => {
+                utils::warning_message(&format!("상태 변경 실패: {}", e));
+            }
+        }
+    }
+    
+    Ok(())
+}
+
 pub async fn run() -> AppResult<()> {
     let config = Config::load()?;
     config.validate()?;
@@ -15,6 +115,10 @@ pub async fn run() -> AppResult<()> {
     let git_ops = GitOps::open()?;
     let current_branch = git_ops.get_current_branch()?;
     
+    let base_branch = git_ops
+        .get_branch_base(&current_branch)?
+        .unwrap_or_else(|| config.default_branch.clone());
+    
     if current_branch == config.default_branch {
         utils::error_message(&format!("기본 브랜치({})에서는 PR을 생성할 수 없습니다.", config.default_branch));
         return Ok(());
@@ -28,31 +132,38 @@ pub async fn run() -> AppResult<()> {
         }
     }
     
-    utils::rocket_message(&format!("브랜치 '{}'에서 '{}'으로 PR 생성", current_branch, config.default_branch));
+    utils::rocket_message(&format!("브랜치 '{}'에서 '{}'으로 PR 생성", current_branch, base_branch));
     println!();
     
-    let context = AppContext::new(config).init_clients().await?;
-    
-    let ticket_key = if current_branch.len() >= 6 && current_branch.starts_with("EM-") {
-        current_branch.clone()
-    } else {
-        utils::warning_message("브랜치명에서 티켓 번호를 추출할 수 없습니다.");
-        return Ok(());
+    let ticket_key = match config.ticket_key_parser()?.extract(&current_branch) {
+        Some(ticket_key) => ticket_key,
+        None => {
+            utils::warning_message("브랜치명에서 티켓 번호를 추출할 수 없습니다.");
+            return Ok(());
+        }
     };
     
-    let spinner = utils::create_spinner(&format!("Jira 티켓 {} 정보 조회 중...", ticket_key));
+    let context = AppContext::new(config).init_clients().await?;
+    
+    let tracker = context.issue_tracker()?;
+    let spinner = utils::create_spinner(&format!("{} 티켓 {} 정보 조회 중...", tracker.name(), ticket_key));
     
-    let (title, body) = match context.jira_client()?.get_issue(&ticket_key).await {
+    let (title, body) = match tracker.get_issue(&ticket_key).await {
         Ok(issue) => {
             spinner.finish_and_clear();
             let title = format!("[{}] {}", issue.key, issue.fields.summary);
-            let jira_url = context.config().get_jira_ticket_url(&issue.key);
+            let jira_url = context.config().get_ticket_url(&issue.key);
+            let description = issue.fields.description
+                .as_ref()
+                .map(adf::to_markdown)
+                .unwrap_or_default();
             
-            let body = if let Some(template) = get_pr_template(&context.config()) {
+            let body = if let Some(template) = get_pr_template(context.config()) {
                 template
                     .replace("{{TICKET_KEY}}", &issue.key)
                     .replace("{{TICKET_URL}}", &jira_url)
                     .replace("{{TICKET_TITLE}}", &issue.fields.summary)
+                    .replace("{{TICKET_DESCRIPTION}}", &description)
                     .replace("{{BRANCH_NAME}}", &current_branch)
             } else {
                 format!(
@@ -66,13 +177,14 @@ pub async fn run() -> AppResult<()> {
             spinner.finish_and_clear();
             utils::warning_message(&format!("티켓 정보 조회 실패: {}", e));
             let title = format!("[{}] 제목을 입력해주세요", ticket_key);
-            let jira_url = context.config().get_jira_ticket_url(&ticket_key);
+            let jira_url = context.config().get_ticket_url(&ticket_key);
             
-            let body = if let Some(template) = get_pr_template(&context.config()) {
+            let body = if let Some(template) = get_pr_template(context.config()) {
                 template
                     .replace("{{TICKET_KEY}}", &ticket_key)
                     .replace("{{TICKET_URL}}", &jira_url)
                     .replace("{{TICKET_TITLE}}", "제목을 입력해주세요")
+                    .replace("{{TICKET_DESCRIPTION}}", "")
                     .replace("{{BRANCH_NAME}}", &current_branch)
             } else {
                 format!(
@@ -84,59 +196,41 @@ pub async fn run() -> AppResult<()> {
         }
     };
     
-    let spinner = utils::create_spinner("GitHub에 PR 생성 중...");
+    let host = context.code_host()?;
+    let label = host.change_request_label();
+    let spinner = utils::create_spinner(&format!("{}에 {} 생성 중...", host.name(), label));
     
-    match context.github_client()?.create_pull_request(
+    match host.create_change_request(
         &title,
         &body,
         &current_branch,
-        &context.config().default_branch,
+        &base_branch,
     ).await {
         Ok(pr) => {
             spinner.finish_and_clear();
-            utils::success_message(&format!("PR이 성공적으로 생성되었습니다! #{}", pr.number));
-            utils::info_message(&format!("PR 링크: {}", pr.html_url));
+            utils::success_message(&format!("{}이 성공적으로 생성되었습니다! {}", label, pr.reference));
+            utils::info_message(&format!("{} 링크: {}", label, pr.url));
             
-            if let Ok(issue) = context.jira_client()?.get_issue(&ticket_key).await {
-                if issue.fields.status.name.to_lowercase() == "in progress" || 
-                   issue.fields.status.name == "진행 중" {
-                    
-                    utils::info_message(&format!("현재 티켓 상태: {}", issue.fields.status.name));
-                    utils::info_message("PR이 생성되었습니다. PR Merge 시 'Done'으로 변경됩니다.");
-                } else if issue.fields.status.name.to_lowercase() == "done" || 
-                          issue.fields.status.name == "완료" {
-                    utils::info_message("티켓이 이미 'Done' 상태입니다.");
-                } else {
-                    utils::info_message(&format!("현재 티켓 상태: {}", issue.fields.status.name));
-                }
+            link_pull_request(&context, &ticket_key, &pr).await;
+            update_ticket_status(&context, &ticket_key).await?;
+            
+            if let Err(e) = worklog::offer_timer_worklog(&context, &ticket_key, TimerFinish::Restart).await {
+                utils::warning_message(&format!("작업 시간 기록 실패: {}", e));
             }
         }
         Err(e) => {
             spinner.finish_and_clear();
             let error_msg = format!("{}", e);
             if error_msg.contains("already exists") {
-                utils::warning_message(&format!("브랜치 '{}'에 대한 PR이 이미 존재합니다.", current_branch));
-                let pr_url = format!("https://github.com/{}/{}/pulls?q=is%3Apr+head%3A{}", 
-                    context.config().repo_owner, 
-                    context.config().repo_name, 
-                    current_branch);
-                utils::info_message(&format!("PR 확인: {}", pr_url));
+                utils::warning_message(&format!("브랜치 '{}'에 대한 {}이 이미 존재합니다.", current_branch, label));
+                utils::info_message(&format!("{} 확인: {}", label, host.branch_search_url(&current_branch)));
                 
-                if let Ok(issue) = context.jira_client()?.get_issue(&ticket_key).await {
-                    if issue.fields.status.name.to_lowercase() == "in progress" || 
-                       issue.fields.status.name == "진행 중" {
-                        
-                        utils::info_message(&format!("현재 티켓 상태: {}", issue.fields.status.name));
-                        utils::info_message("PR이 이미 생성되어 있습니다. PR Merge시 'Done'으로 변경됩니다.");
-                    } else if issue.fields.status.name.to_lowercase() == "done" || 
-                              issue.fields.status.name == "완료" {
-                        utils::info_message("티켓이 이미 'Done' 상태입니다.");
-                    } else {
-                        utils::info_message(&format!("현재 티켓 상태: {}", issue.fields.status.name));
-                    }
+                if let Ok(Some(pr)) = host.find_change_request(&current_branch).await {
+                    link_pull_request(&context, &ticket_key, &pr).await;
                 }
+                update_ticket_status(&context, &ticket_key).await?;
             } else {
-                utils::error_message(&format!("PR 생성 실패: {}", e));
+                utils::error_message(&format!("{} 생성 실패: {}", label, e));
             }
         }
     }
@@ -0,0 +1,111 @@
+use crate::{AppError, AppErrorType, AppResult, AppContext, Config, git::GitOps, utils};
+use crate::error::AppErrorExt;
+use crate::jira::{adf, Issue};
+use chrono::Local;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+const DEFAULT_TEMPLATE: &str = "## {{TO}} ({{DATE}})\n\n{{CHANGES}}\n";
+const DEFAULT_ITEM: &str = "- [{{KEY}}]({{URL}}) {{SUMMARY}}";
+
+fn render_item(config: &Config, template: &str, issue: &Issue) -> String {
+    template
+        .replace("{{KEY}}", &issue.key)
+        .replace("{{URL}}", &config.get_ticket_url(&issue.key))
+        .replace("{{SUMMARY}}", &adf::escape_markdown(&issue.fields.summary))
+        .replace("{{TYPE}}", &issue.fields.issuetype.name)
+        .replace("{{STATUS}}", &issue.fields.status.name)
+        .replace("{{ASSIGNEE}}", &issue.format_assignee())
+}
+
+// 이슈 타입별로 묶고, Jira에서 찾지 못한 키는 마지막에 따로 표시합니다
+fn render_changes(config: &Config, issues: &[Issue], missing: &[String]) -> String {
+    let item_template = config.release_notes_item.as_deref().unwrap_or(DEFAULT_ITEM);
+    let mut groups: BTreeMap<&str, Vec<&Issue>> = BTreeMap::new();
+    for issue in issues {
+        groups.entry(issue.fields.issuetype.name.as_str()).or_default().push(issue);
+    }
+    
+    let mut sections: Vec<String> = groups
+        .into_iter()
+        .map(|(issue_type, issues)| {
+            let items: Vec<String> = issues
+                .into_iter()
+                .map(|issue| render_item(config, item_template, issue))
+                .collect();
+            format!("### {}\n\n{}", issue_type, items.join("\n"))
+        })
+        .collect();
+    
+    if !missing.is_empty() {
+        let items: Vec<String> = missing
+            .iter()
+            .map(|key| format!("- [{}]({})", key, config.get_ticket_url(key)))
+            .collect();
+        sections.push(format!("### 기타\n\n{}", items.join("\n")));
+    }
+    
+    sections.join("\n\n")
+}
+
+pub async fn run(range: String, output: Option<PathBuf>, template: Option<PathBuf>) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let git_ops = GitOps::open()?;
+    let keys = git_ops.ticket_keys_in_range(&config.ticket_key_parser()?, &range)?;
+    
+    if keys.is_empty() {
+        return Err(AppError::not_found(format!("'{}' 범위의 커밋에서 티켓 번호를 찾을 수 없습니다", range)));
+    }
+    
+    let template = match template {
+        Some(path) => fs::read_to_string(&path)
+            .with_app_type(AppErrorType::IoError(format!("템플릿 파일을 읽을 수 없습니다: {}", path.display())))?,
+        None => match &config.release_notes_path {
+            Some(path) => fs::read_to_string(path)
+                .with_app_type(AppErrorType::ConfigError(format!("releaseNotes.path 템플릿 파일을 읽을 수 없습니다: {}", path.display())))?,
+            None => config.release_notes_template.clone().unwrap_or_else(|| DEFAULT_TEMPLATE.to_string()),
+        },
+    };
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let config = context.config();
+    
+    let tracker = context.issue_tracker()?;
+    let spinner = utils::create_spinner(&format!("{}개 티켓 정보를 {}에서 조회하고 있습니다...", keys.len(), tracker.name()));
+    let issues = tracker.get_issues(&keys).await;
+    spinner.finish_and_clear();
+    let issues = issues?;
+    
+    let missing: Vec<String> = keys
+        .iter()
+        .filter(|key| !issues.iter().any(|issue| &issue.key == *key))
+        .cloned()
+        .collect();
+    
+    let (from, to) = GitOps::split_range(&range);
+    
+    let notes = template
+        .replace("{{RANGE}}", &range)
+        .replace("{{FROM}}", from)
+        .replace("{{TO}}", to)
+        .replace("{{DATE}}", &Local::now().format("%Y-%m-%d").to_string())
+        .replace("{{TICKET_COUNT}}", &keys.len().to_string())
+        .replace("{{CHANGES}}", &render_changes(config, &issues, &missing));
+    
+    match output {
+        Some(path) => {
+            fs::write(&path, &notes)
+                .with_app_type(AppErrorType::IoError(format!("파일 쓰기 실패: {}", path.display())))?;
+            utils::success_message(&format!("릴리스 노트를 {}에 저장했습니다 (티켓 {}개)", path.display(), keys.len()));
+            if !missing.is_empty() {
+                utils::warning_message(&format!("{}에서 찾을 수 없는 티켓: {}", tracker.name(), missing.join(", ")));
+            }
+        }
+        None => print!("{}", notes),
+    }
+    
+    Ok(())
+}
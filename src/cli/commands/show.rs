@@ -0,0 +1,228 @@
+use crate::{AppError, AppResult, AppContext, Config, git::GitOps, utils};
+use crate::jira::{adf, Comment, Issue, IssueRef, Transition};
+use colored::Colorize;
+
+const RECENT_COMMENT_COUNT: usize = 5;
+
+fn section(title: &str) {
+    println!();
+    println!("{}", title.bold());
+}
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| format!("   {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn format_issue_ref(issue: &IssueRef) -> String {
+    let status = issue.status().map(|status| format!(" [{}]", status.name)).unwrap_or_default();
+    format!("{} {}{}", issue.key.cyan(), issue.summary(), status.dimmed())
+}
+
+fn print_details(context: &AppContext, issue: &Issue) {
+    let fields = &issue.fields;
+    let join_or_none = |values: Vec<String>| if values.is_empty() { "없음".to_string() } else { values.join(", ") };
+    
+    println!("{} {}", issue.key.bold().cyan(), fields.summary.bold());
+    println!("   타입: {} | 상태: {} | 우선순위: {}", fields.issuetype.name, fields.status.name.yellow(), issue.format_priority());
+    println!(
+        "   담당자: {} | 보고자: {}",
+        issue.format_assignee(),
+        fields.reporter.as_ref().and_then(|user| user.display_name.clone()).unwrap_or_else(|| "없음".to_string())
+    );
+    println!(
+        "   생성: {} | 수정: {}",
+        fields.created.format("%Y-%m-%d %H:%M"),
+        fields.updated.format("%Y-%m-%d %H:%M")
+    );
+    
+    if let Some(ref parent) = fields.parent {
+        println!("   상위 티켓: {}", format_issue_ref(parent));
+    }
+    
+    println!("   라벨: {}", join_or_none(fields.labels.clone()));
+    println!("   컴포넌트: {}", join_or_none(fields.components.iter().map(|c| c.name.clone()).collect()));
+    println!(
+        "   수정 버전: {}",
+        join_or_none(fields.fix_versions
+            .iter()
+            .map(|version| if version.released { format!("{} (릴리스됨)", version.name) } else { version.name.clone() })
+            .collect())
+    );
+    println!("   링크: {}", context.config().get_ticket_url(&issue.key).dimmed());
+}
+
+fn print_description(issue: &Issue) {
+    section("📝 설명");
+    
+    let description = issue.fields.description
+        .as_ref()
+        .map(adf::to_terminal)
+        .filter(|description| !description.trim().is_empty());
+    
+    match description {
+        Some(description) => println!("{}", indent(&description)),
+        None => println!("   {}", "설명 없음".dimmed()),
+    }
+}
+
+fn print_relations(issue: &Issue) {
+    let fields = &issue.fields;
+    
+    if !fields.issuelinks.is_empty() {
+        section("🔗 연결된 이슈");
+        for link in &fields.issuelinks {
+            let Some((relation, linked)) = link.relation() else {
+                continue;
+            };
+            // 아직 완료되지 않은 선행 티켓은 눈에 띄게 표시
+            let marker = if link.blocker().is_some() && !linked.is_done() { "⛔ ".red().to_string() } else { String::new() };
+            println!("   {}{} {}", marker, relation.purple(), format_issue_ref(linked));
+        }
+    }
+    
+    if !fields.subtasks.is_empty() {
+        section(&format!("🧩 하위 작업 ({})", fields.subtasks.len()));
+        for subtask in &fields.subtasks {
+            println!("   {}", format_issue_ref(subtask));
+        }
+    }
+    
+    if !fields.attachments.is_empty() {
+        section(&format!("📎 첨부파일 ({})", fields.attachments.len()));
+        for attachment in &fields.attachments {
+            println!("   {} ({})", attachment.filename, attachment.format_size());
+            if let Some(ref url) = attachment.content {
+                println!("     {}", url.dimmed());
+            }
+        }
+    }
+}
+
+fn print_comments(comments: &[Comment], show_all: bool) {
+    section(&format!("💬 코멘트 ({})", comments.len()));
+    
+    if comments.is_empty() {
+        println!("   {}", "코멘트 없음".dimmed());
+        return;
+    }
+    
+    let skipped = if show_all { 0 } else { comments.len().saturating_sub(RECENT_COMMENT_COUNT) };
+    if skipped > 0 {
+        println!("   {}", format!("이전 코멘트 {}개 생략 (--all-comments로 전체 보기)", skipped).dimmed());
+    }
+    
+    for comment in &comments[skipped..] {
+        let author = comment.author
+            .as_ref()
+            .and_then(|user| user.display_name.clone())
+            .unwrap_or_else(|| "알 수 없음".to_string());
+        let created = comment.created
+            .map(|created| created.format("%Y-%m-%d %H:%M").to_string())
+            .unwrap_or_default();
+        
+        println!();
+        println!("   {} {}", author.bold(), created.dimmed());
+        if let Some(ref body) = comment.body {
+            println!("{}", indent(&indent(&adf::to_terminal(body))));
+        }
+    }
+}
+
+fn print_transitions(transitions: &[Transition]) {
+    section("🔀 가능한 상태 전환");
+    
+    if transitions.is_empty() {
+        println!("   {}", "전환 가능한 상태 없음".dimmed());
+        return;
+    }
+    
+    for transition in transitions {
+        if transition.name == transition.to.name {
+            println!("   {}", transition.name);
+        } else {
+            println!("   {} → {}", transition.name, transition.to.name);
+        }
+    }
+}
+
+async fn print_development(context: &AppContext, ticket: &str) -> AppResult<()> {
+    section("🌿 개발 정보");
+    
+    let git_ops = GitOps::open()?;
+    let key_parser = context.config().ticket_key_parser()?;
+    let Some(branch) = git_ops.find_ticket_branch(&key_parser, ticket)? else {
+        println!("   로컬 브랜치: {}", "없음".dimmed());
+        return Ok(());
+    };
+    
+    let is_current = git_ops.get_current_branch().is_ok_and(|current| current == branch);
+    let base = git_ops.get_branch_base(&branch)?.unwrap_or_else(|| context.config().default_branch.clone());
+    println!(
+        "   로컬 브랜치: {}{} → {}",
+        branch.green(),
+        if is_current { " (현재 브랜치)" } else { "" },
+        base
+    );
+    
+    let Ok(host) = context.code_host() else {
+        return Ok(());
+    };
+    let label = host.change_request_label();
+    
+    match host.find_change_request(&branch).await {
+        Ok(Some(pr)) => {
+            println!("   {}: {} {} ({})", label, pr.reference, pr.title, pr.format_state());
+            println!("       {}", pr.url.dimmed());
+        }
+        Ok(None) => println!("   {}: {}", label, "없음".dimmed()),
+        Err(e) => println!("   {}: {}", label, format!("조회 실패 - {}", e).dimmed()),
+    }
+    
+    Ok(())
+}
+
+pub async fn run(ticket: Option<String>, show_all_comments: bool) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let ticket = match ticket {
+        Some(ticket) => utils::format_ticket_key(&config, ticket.trim()),
+        None => GitOps::open()?
+            .get_current_ticket_key(&config.ticket_key_parser()?)?
+            .ok_or_else(|| AppError::validation_error("현재 브랜치에서 티켓 번호를 찾을 수 없습니다. 티켓 번호를 지정해주세요"))?,
+    };
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let tracker = context.issue_tracker()?;
+    
+    let spinner = utils::create_spinner(&format!("{} 티켓 {} 정보 조회 중...", tracker.name(), ticket));
+    let issue = tracker.get_issue(&ticket).await;
+    let comments = tracker.get_comments(&ticket).await;
+    let transitions = tracker.get_transitions(&ticket).await;
+    spinner.finish_and_clear();
+    let issue = issue?;
+    
+    print_details(&context, &issue);
+    print_description(&issue);
+    print_relations(&issue);
+    
+    match comments {
+        Ok(comments) => print_comments(&comments, show_all_comments),
+        Err(e) => utils::warning_message(&format!("코멘트 조회 실패: {}", e)),
+    }
+    
+    match transitions {
+        Ok(transitions) => print_transitions(&transitions),
+        Err(e) => utils::warning_message(&format!("상태 전환 조회 실패: {}", e)),
+    }
+    
+    if let Err(e) = print_development(&context, &issue.key).await {
+        utils::warning_message(&format!("브랜치 정보 조회 실패: {}", e));
+    }
+    
+    println!();
+    Ok(())
+}
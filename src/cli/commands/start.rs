@@ -1,14 +1,144 @@
-use crate::{AppResult, AppContext, Config, git::GitOps, utils};
+use crate::{AppError, AppResult, AppContext, Config, git::GitOps, utils};
+use crate::cli::{workflow, worklog};
+use crate::jira::{Issue, IssueUpdate, SprintSelector, TransitionTarget, WorkflowStage};
+use colored::Colorize;
 
-pub async fn run(ticket: String) -> AppResult<()> {
-    let ticket = ticket.trim().to_uppercase();
+// 담당자 지정과 라벨/컴포넌트 추가를 한 번의 확인 후 하나의 요청으로 반영합니다
+async fn stamp_issue(context: &AppContext, issue: &Issue) -> AppResult<()> {
+    let tracker = context.issue_tracker()?;
+    let start_config = &context.config().start;
+    let mut update = IssueUpdate::default();
+    let mut changes = Vec::new();
     
-    utils::rocket_message(&format!("티켓 {} 작업을 시작합니다", ticket));
-    println!();
+    if start_config.assign_self {
+        let myself = tracker.get_myself().await?;
+        let assignee = issue.fields.assignee.as_ref();
+        
+        if myself.can_be_assigned() && !assignee.is_some_and(|user| myself.is_same(user)) {
+            update = update.assign(&myself);
+            changes.push(format!("담당자: {} → {}", issue.format_assignee(), myself.display_name.as_deref().unwrap_or("나").bold()));
+        }
+    }
+    
+    let labels: Vec<String> = start_config.labels
+        .iter()
+        .filter(|label| !issue.fields.labels.contains(label))
+        .cloned()
+        .collect();
+    if !labels.is_empty() {
+        changes.push(format!("라벨 추가: {}", labels.join(", ").bold()));
+        update = update.add_labels(&labels);
+    }
+    
+    let components: Vec<String> = start_config.components
+        .iter()
+        .filter(|name| !issue.fields.components.iter().any(|component| &component.name == *name))
+        .cloned()
+        .collect();
+    if !components.is_empty() {
+        changes.push(format!("컴포넌트 추가: {}", components.join(", ").bold()));
+        update = update.add_components(&components);
+    }
+    
+    if update.is_empty() {
+        return Ok(());
+    }
+    
+    utils::ticket_message(&format!("티켓 {}에 다음 변경사항을 적용합니다:", issue.key));
+    for change in &changes {
+        println!("   - {}", change);
+    }
+    
+    if !utils::prompt_confirmation("변경사항을 적용하시겠습니까?")? {
+        utils::info_message("티켓 변경을 건너뜁니다");
+        return Ok(());
+    }
+    
+    let spinner = utils::create_spinner(&format!("티켓 {} 업데이트 중...", issue.key));
+    let result = tracker.update_issue(&issue.key, &update).await;
+    spinner.finish_and_clear();
+    result?;
+    
+    utils::success_message("티켓 정보가 업데이트되었습니다");
+    Ok(())
+}
+
+async fn add_to_active_sprint(context: &AppContext, ticket: &str) -> AppResult<()> {
+    let jira_client = context.jira_client()?;
+    
+    if let Some(sprint) = jira_client.get_issue_sprint(ticket).await?.filter(|sprint| sprint.is_open()) {
+        utils::info_message(&format!("티켓이 이미 스프린트 '{}'에 포함되어 있습니다", sprint.name));
+        return Ok(());
+    }
+    
+    let board_ids = context.board_ids().await?;
+    let Some(sprint) = jira_client.find_sprint(&board_ids, &SprintSelector::Current).await? else {
+        utils::warning_message("활성 스프린트가 없습니다");
+        return Ok(());
+    };
+    
+    let spinner = utils::create_spinner(&format!("티켓을 스프린트 '{}'로 이동 중...", sprint.name));
+    let result = jira_client.move_issues_to_sprint(sprint.id, &[ticket.to_string()]).await;
+    spinner.finish_and_clear();
+    result?;
+    
+    utils::success_message(&format!("티켓이 스프린트 '{}'에 추가되었습니다", sprint.name));
+    Ok(())
+}
+
+// 완료되지 않은 선행 티켓이 있으면 경고 후 확인을 받고, refuseBlocked 설정 시 거부합니다
+fn check_blockers(context: &AppContext, issue: &Issue) -> AppResult<bool> {
+    let blockers = issue.unresolved_blockers();
+    if blockers.is_empty() {
+        return Ok(true);
+    }
+    
+    utils::warning_message(&format!("티켓 {}을(를) 막고 있는 완료되지 않은 티켓이 {}개 있습니다:", issue.key, blockers.len()));
+    for blocker in &blockers {
+        let status = blocker.status().map(|status| status.name.as_str()).unwrap_or("알 수 없음");
+        println!("   {} {} {}", "⛔".red(), blocker.format_summary(), format!("[{}]", status).dimmed());
+    }
+    
+    if context.config().start.refuse_blocked {
+        let keys: Vec<&str> = blockers.iter().map(|blocker| blocker.key.as_str()).collect();
+        return Err(AppError::validation_error(format!("선행 티켓({})이 완료된 후 작업을 시작할 수 있습니다", keys.join(", "))));
+    }
+    
+    utils::prompt_confirmation("그래도 작업을 시작하시겠습니까?")
+}
+
+// 하위 작업이면 상위 티켓의 로컬 브랜치를 찾아 분기할지 묻습니다
+fn select_base_branch(context: &AppContext, git_ops: &GitOps, issue: &Issue) -> AppResult<Option<String>> {
+    if !issue.is_subtask() {
+        return Ok(None);
+    }
+    
+    let Some(parent) = issue.fields.parent.as_ref() else {
+        return Ok(None);
+    };
+    
+    utils::ticket_message(&format!("상위 티켓: {}", parent.format_summary()));
+    
+    let key_parser = context.config().ticket_key_parser()?;
+    let Some(parent_branch) = git_ops.find_ticket_branch(&key_parser, &parent.key)? else {
+        utils::info_message(&format!("상위 티켓 {}의 로컬 브랜치가 없어 기본 브랜치에서 분기합니다", parent.key));
+        return Ok(None);
+    };
+    
+    let use_parent = utils::prompt_confirmation(&format!("상위 티켓 브랜치 '{}'에서 분기하시겠습니까?", parent_branch))?;
     
+    Ok(use_parent.then_some(parent_branch))
+}
+
+pub async fn run(ticket: String, add_to_sprint: bool) -> AppResult<()> {
     let config = Config::load()?;
     config.validate()?;
     
+    let ticket = utils::format_ticket_key(&config, &ticket);
+    
+    utils::rocket_message(&format!("티켓 {} 작업을 시작합니다", ticket));
+    println!();
+    
     let git_ops = GitOps::open()?;
     
     let current_branch = git_ops.get_current_branch()?;
@@ -20,7 +150,47 @@ pub async fn run(ticket: String) -> AppResult<()> {
         return Ok(());
     }
     
-    let branch_name = config.format_branch_name(&ticket, None);
+    let context = AppContext::new(config).init_clients().await?;
+    let config = context.config();
+    
+    let tracker = context.issue_tracker()?;
+    let spinner = utils::create_spinner(&format!("{} 티켓 {} 정보 조회 중...", tracker.name(), ticket));
+    
+    let issue = match tracker.get_issue(&ticket).await {
+        Ok(issue) => {
+            spinner.finish_and_clear();
+            utils::ticket_message(&format!("티켓: {}", issue.format_summary()));
+            utils::info_message(&format!("상태: {}", issue.fields.status.name));
+            Some(issue)
+        }
+        Err(e) => {
+            spinner.finish_and_clear();
+            // 선행 티켓을 확인할 수 없으므로 refuseBlocked 설정 시에는 진행하지 않습니다
+            if config.start.refuse_blocked {
+                return Err(AppError::validation_error(format!("티켓 정보를 조회할 수 없어 선행 티켓을 확인하지 못했습니다: {}", e)));
+            }
+            utils::warning_message(&format!("티켓 정보 조회 실패: {}", e));
+            None
+        }
+    };
+    
+    // 브랜치 이름, 타이머, 링크에는 트래커가 돌려준 정식 키를 사용합니다
+    let ticket = issue.as_ref().map_or(ticket, |issue| issue.key.clone());
+    
+    if let Some(ref issue) = issue
+        && !check_blockers(&context, issue)? {
+        utils::info_message("작업 시작을 취소했습니다");
+        return Ok(());
+    }
+    
+    let branch_name = match &issue {
+        Some(issue) => config.format_branch_name(
+            &ticket,
+            Some(&issue.fields.issuetype.name),
+            Some(&issue.fields.summary),
+        ),
+        None => config.format_branch_name(&ticket, None, None),
+    };
     
     if git_ops.branch_exists(&branch_name)? {
         utils::warning_message(&format!("브랜치 '{}'가 이미 존재합니다.", branch_name));
@@ -34,56 +204,82 @@ pub async fn run(ticket: String) -> AppResult<()> {
         return Ok(());
     }
     
-    let spinner = utils::create_spinner(&format!("기본 브랜치({})에서 최신 변경사항을 가져오는 중...", config.default_branch));
-    git_ops.pull_latest(&config.default_branch)?;
-    spinner.finish_with_message(format!("최신 변경사항을 가져왔습니다"));
+    let parent_branch = match &issue {
+        Some(issue) => select_base_branch(&context, &git_ops, issue)?,
+        None => None,
+    };
+    
+    match parent_branch {
+        Some(ref parent_branch) => {
+            // 상위 티켓 브랜치는 원격에 없을 수 있으므로 pull 실패는 경고만 표시
+            let spinner = utils::create_spinner(&format!("상위 브랜치({})에서 최신 변경사항을 가져오는 중...", parent_branch));
+            let result = git_ops.pull_latest(parent_branch);
+            spinner.finish_and_clear();
+            if let Err(e) = result {
+                utils::warning_message(&format!("상위 브랜치 pull 실패, 로컬 브랜치 기준으로 진행합니다: {}", e));
+            }
+        }
+        None => {
+            let spinner = utils::create_spinner(&format!("기본 브랜치({})에서 최신 변경사항을 가져오는 중...", config.default_branch));
+            git_ops.pull_latest(&config.default_branch)?;
+            spinner.finish_with_message("최신 변경사항을 가져왔습니다");
+        }
+    }
+    
+    let base_branch = parent_branch.as_deref().unwrap_or(&config.default_branch);
     
     let spinner = utils::create_spinner(&format!("새 브랜치 '{}' 생성 중...", branch_name));
-    git_ops.create_and_checkout_branch(&branch_name, &config.default_branch)?;
+    git_ops.create_and_checkout_branch(&branch_name, base_branch)?;
     spinner.finish_and_clear();
-    utils::success_message(&format!("브랜치 '{}'가 생성되고 체크아웃되었습니다", branch_name));
+    utils::success_message(&format!("브랜치 '{}'가 '{}'에서 생성되고 체크아웃되었습니다", branch_name, base_branch));
     
-    let context = AppContext::new(config).init_clients().await?;
+    if let Some(ref parent_branch) = parent_branch
+        && let Err(e) = git_ops.set_branch_base(&branch_name, parent_branch) {
+        utils::warning_message(&format!("PR 대상 브랜치 저장 실패: {}", e));
+    }
     
-    let spinner = utils::create_spinner(&format!("Jira 티켓 {} 정보 조회 중...", ticket));
+    worklog::start_timer(&context, &ticket);
     
-    match context.jira_client()?.get_issue(&ticket).await {
-        Ok(issue) => {
-            spinner.finish_and_clear();
-            utils::ticket_message(&format!("티켓: {}", issue.format_summary()));
-            utils::info_message(&format!("상태: {}", issue.fields.status.name));
+    match issue {
+        Some(issue) => {
+            if let Err(e) = stamp_issue(&context, &issue).await {
+                utils::warning_message(&format!("티켓 업데이트 실패: {}", e));
+            }
+            
+            let start_label = config.workflow.label(WorkflowStage::Start);
             
-            if issue.fields.status.name.to_lowercase() != "in progress" && 
-               issue.fields.status.name != "진행 중" {
+            if !config.workflow.is_in_stage(&issue.fields.status, WorkflowStage::Start) {
                 
-                let should_update = utils::prompt_confirmation("티켓 상태를 'In Progress'로 변경하시겠습니까?")?;
+                let should_update = utils::prompt_confirmation(&format!("티켓 상태를 '{}'로 변경하시겠습니까?", start_label))?;
                 
                 if should_update {
-                    let spinner = utils::create_spinner("티켓 상태를 'In Progress'로 변경 중...");
-                    
-                    match context.jira_client()?.transition_to_status(&ticket, "In Progress").await {
-                        Ok(()) => {
-                            spinner.finish_and_clear();
-                            utils::success_message("티켓 상태가 'In Progress'로 변경되었습니다");
+                    match workflow::move_issue(&context, &ticket, TransitionTarget::Stage(WorkflowStage::Start)).await {
+                        Ok(true) => {
+                            utils::success_message(&format!("티켓 상태가 '{}'로 변경되었습니다", start_label));
+                        }
+                        Ok(false) => {
+                            utils::info_message("상태 변경을 취소했습니다");
                         }
                         Err(e) => {
-                            spinner.finish_and_clear();
                             utils::warning_message(&format!("상태 변경 실패: {}", e));
-                            utils::info_message("수동으로 Jira에서 상태를 변경해주세요");
+                            utils::info_message(&format!("수동으로 {}에서 상태를 변경해주세요", tracker.name()));
                         }
                     }
                 }
             } else {
-                utils::success_message("티켓이 이미 'In Progress' 상태입니다");
+                utils::success_message(&format!("티켓이 이미 '{}' 상태입니다", issue.fields.status.name));
             }
             
-            let url = context.config().get_jira_ticket_url(&ticket);
+            if add_to_sprint
+                && let Err(e) = add_to_active_sprint(&context, &ticket).await {
+                utils::warning_message(&format!("스프린트 추가 실패: {}", e));
+            }
+            
+            let url = config.get_ticket_url(&ticket);
             utils::info_message(&format!("티켓 링크: {}", url));
         }
-        Err(e) => {
-            spinner.finish_and_clear();
-            utils::warning_message(&format!("티켓 정보 조회 실패: {}", e));
-            utils::info_message("브랜치는 생성되었습니다. 수동으로 Jira 상태를 확인해주세요");
+        None => {
+            utils::info_message(&format!("브랜치는 생성되었습니다. 수동으로 {} 상태를 확인해주세요", tracker.name()));
         }
     }
     
@@ -0,0 +1,53 @@
+use crate::{AppError, AppResult, Config, git::GitOps, utils};
+use crate::cli::commands::create::{self, CreateArgs};
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug)]
+pub enum SubtaskCommand {
+    #[command(about = "현재 티켓 아래에 하위 작업 생성")]
+    Add {
+        #[arg(help = "하위 작업 제목 (생략하면 입력)")]
+        summary: Option<String>,
+        
+        #[arg(long, help = "상위 티켓 번호 (기본값: 현재 브랜치의 티켓)")]
+        parent: Option<String>,
+        
+        #[arg(short = 't', long = "type", help = "하위 작업 이슈 타입")]
+        issue_type: Option<String>,
+        
+        #[arg(short, long, help = "하위 작업 설명")]
+        description: Option<String>,
+        
+        #[arg(long, help = "생성 후 바로 작업 시작 (브랜치 생성)")]
+        start: bool,
+    },
+}
+
+pub async fn run(command: SubtaskCommand) -> AppResult<()> {
+    match command {
+        SubtaskCommand::Add { summary, parent, issue_type, description, start } => {
+            let config = Config::load()?;
+            config.validate()?;
+            
+            let parent = match parent {
+                Some(parent) => utils::format_ticket_key(&config, parent.trim()),
+                None => GitOps::open()?
+                    .get_current_ticket_key(&config.ticket_key_parser()?)?
+                    .ok_or_else(|| AppError::validation_error("현재 브랜치에서 티켓 번호를 찾을 수 없습니다. --parent로 상위 티켓을 지정해주세요"))?,
+            };
+            
+            utils::ticket_message(&format!("상위 티켓 {} 아래에 하위 작업을 생성합니다", parent));
+            
+            let args = CreateArgs {
+                issue_type,
+                summary,
+                description,
+                parent: Some(parent),
+                start,
+                ..CreateArgs::default()
+            };
+            
+            create::create_issue(args, true).await
+        }
+    }
+}
@@ -1,4 +1,7 @@
 use crate::{AppResult, AppContext, Config, git::GitOps, utils};
+use crate::cli::workflow;
+use crate::cli::worklog::{self, TimerFinish};
+use crate::jira::{TransitionTarget, WorkflowStage};
 
 pub async fn run() -> AppResult<()> {
     let config = Config::load()?;
@@ -19,10 +22,12 @@ pub async fn run() -> AppResult<()> {
     git_ops.pull_latest(&config.default_branch)?;
     spinner.finish_and_clear();
     
+    let key_parser = config.ticket_key_parser()?;
     let branches = git_ops.list_branches()?;
-    let ticket_branches: Vec<String> = branches
+    let ticket_branches: Vec<(String, String)> = branches
         .into_iter()
-        .filter(|branch| branch.starts_with("EM-") && branch != &config.default_branch)
+        .filter(|branch| branch != &config.default_branch)
+        .filter_map(|branch| key_parser.extract(&branch).map(|key| (branch, key)))
         .collect();
     
     if ticket_branches.is_empty() {
@@ -34,45 +39,50 @@ pub async fn run() -> AppResult<()> {
     
     let context = AppContext::new(config).init_clients().await?;
     
-    for branch in ticket_branches {
+    for (branch, ticket_key) in ticket_branches {
         println!();
         utils::branch_message(&format!("브랜치 '{}' 확인 중...", branch));
         
-        let is_merged = check_if_merged(&git_ops, &branch, &context.config().default_branch)?;
+        let merged_locally = check_if_merged(&git_ops, &branch, &context.config().default_branch)?;
+        // squash/rebase 머지는 로컬 이력으로 확인할 수 없어 PR/MR 상태를 확인합니다
+        let is_merged = merged_locally || is_change_request_merged(&context, &git_ops, &branch).await;
         
         if is_merged {
             utils::success_message(&format!("브랜치 '{}'가 머지되었습니다.", branch));
             
-            let should_update_jira = utils::prompt_confirmation(&format!("티켓 {}를 'Done' 상태로 변경하시겠습니까?", branch))?;
+            let done_label = context.config().workflow.label(WorkflowStage::Done);
+            let should_update_jira = utils::prompt_confirmation(&format!("티켓 {}를 '{}' 상태로 변경하시겠습니까?", ticket_key, done_label))?;
             
             if should_update_jira {
-                match context.jira_client()?.get_issue(&branch).await {
+                match context.issue_tracker()?.get_issue(&ticket_key).await {
                     Ok(issue) => {
-                        if issue.fields.status.name.to_lowercase() != "done" && 
-                           issue.fields.status.name != "완료" {
+                        if !context.config().workflow.is_in_stage(&issue.fields.status, WorkflowStage::Done) {
                             
-                            let spinner = utils::create_spinner(&format!("티켓 {} 상태를 'Done'으로 변경 중...", branch));
-                            
-                            match context.jira_client()?.transition_to_status(&branch, "Done").await {
-                                Ok(()) => {
-                                    spinner.finish_and_clear();
-                                    utils::success_message(&format!("티켓 {} 상태가 'Done'으로 변경되었습니다", branch));
+                            match workflow::move_issue(&context, &ticket_key, TransitionTarget::Stage(WorkflowStage::Done)).await {
+                                Ok(true) => {
+                                    utils::success_message(&format!("티켓 {} 상태가 '{}'으로 변경되었습니다", ticket_key, done_label));
+                                }
+                                Ok(false) => {
+                                    utils::info_message("상태 변경을 취소했습니다");
                                 }
                                 Err(e) => {
-                                    spinner.finish_and_clear();
                                     utils::warning_message(&format!("상태 변경 실패: {}", e));
                                 }
                             }
                         } else {
-                            utils::info_message(&format!("티켓 {}이 이미 'Done' 상태입니다.", branch));
+                            utils::info_message(&format!("티켓 {}이 이미 '{}' 상태입니다.", ticket_key, issue.fields.status.name));
                         }
                     }
                     Err(e) => {
-                        utils::warning_message(&format!("티켓 {} 조회 실패: {}", branch, e));
+                        utils::warning_message(&format!("티켓 {} 조회 실패: {}", ticket_key, e));
                     }
                 }
             }
             
+            if let Err(e) = worklog::offer_timer_worklog(&context, &ticket_key, TimerFinish::Clear).await {
+                utils::warning_message(&format!("작업 시간 기록 실패: {}", e));
+            }
+            
             let should_delete_branch = utils::prompt_confirmation(&format!("로컬 브랜치 '{}'를 삭제하시겠습니까?", branch))?;
             
             if should_delete_branch {
@@ -82,6 +92,9 @@ pub async fn run() -> AppResult<()> {
                     }
                     Err(e) => {
                         utils::warning_message(&format!("브랜치 삭제 실패: {}", e));
+                        if !merged_locally {
+                            utils::info_message(&format!("squash/rebase 머지된 브랜치는 git이 머지되지 않은 것으로 봅니다. 확인 후 'git branch -D {}'로 직접 삭제하세요", branch));
+                        }
                     }
                 }
             }
@@ -95,6 +108,34 @@ pub async fn run() -> AppResult<()> {
     Ok(())
 }
 
+// 머지 이후 로컬에 추가된 커밋이 있으면 머지되지 않은 것으로 봅니다
+async fn is_change_request_merged(context: &AppContext, git_ops: &GitOps, branch: &str) -> bool {
+    let Ok(host) = context.code_host() else {
+        return false;
+    };
+    
+    match host.find_change_request(branch).await {
+        Ok(Some(pr)) if pr.is_merged() => {
+            let local_commit = git_ops.branch_commit(branch).unwrap_or_default();
+            if pr.contains_commit(&local_commit) {
+                return true;
+            }
+            utils::warning_message(&format!(
+                "{} {}는 머지되었지만 로컬 브랜치 '{}'에 포함되지 않은 커밋이 있습니다",
+                host.change_request_label(), pr.reference, branch
+            ));
+            false
+        }
+        Ok(Some(_)) => false,
+        Ok(None) => false,
+        Err(e) => {
+            utils::warning_message(&format!("{} 상태 조회 실패: {}", host.change_request_label(), e));
+            false
+        }
+    }
+}
+
+#[allow(unused_variables, clippy::needless_borrows_for_generic_args)]
 fn check_if_merged(git_ops: &GitOps, branch: &str, base_branch: &str) -> AppResult<bool> {
     use std::process::Command;
     use crate::error::AppErrorExt;
@@ -108,6 +149,7 @@ fn check_if_merged(git_ops: &GitOps, branch: &str, base_branch: &str) -> AppResu
     Ok(output.status.success())
 }
 
+#[allow(unused_variables, clippy::needless_borrows_for_generic_args)]
 fn delete_branch(git_ops: &GitOps, branch: &str) -> AppResult<()> {
     use std::process::Command;
     use crate::error::AppErrorExt;
@@ -1,53 +1,289 @@
-use crate::{AppResult, AppContext, Config, utils};
+use crate::{AppError, AppResult, AppContext, Config, utils};
 use crate::jira::models::TicketAction;
+use crate::jira::{Clause, Issue, Jql, SearchResults, SortOrder, Status, SprintSelector, WorkflowStage};
+use clap::Args;
 use colored::Colorize;
 
-pub async fn run(limit: Option<i32>, status_filter: Option<String>, interactive: Option<bool>) -> AppResult<()> {
+#[derive(Args, Debug, Default)]
+pub struct TicketsArgs {
+    #[arg(short, long, help = "표시할 최대 티켓 수 (기본값: 50)")]
+    pub limit: Option<usize>,
+    
+    #[arg(short, long, help = "페이지를 모두 조회하여 전체 티켓 표시", conflicts_with = "limit")]
+    pub all: bool,
+    
+    #[arg(short, long, help = "상태별 필터링 (예: In Progress, Done)")]
+    pub status: Option<String>,
+    
+    #[arg(long, help = "JQL로 직접 조회", conflicts_with_all = ["status", "filter"])]
+    pub jql: Option<String>,
+    
+    #[arg(short, long, help = "jgf.json 또는 Jira 즐겨찾기에 저장된 필터 이름으로 조회", conflicts_with = "status")]
+    pub filter: Option<String>,
+    
+    #[arg(long, help = "Jira 즐겨찾기 필터 목록 조회")]
+    pub favourites: bool,
+    
+    #[arg(long, help = "스프린트 티켓 조회 (current, next 또는 스프린트 이름)", conflicts_with_all = ["jql", "filter", "favourites"])]
+    pub sprint: Option<SprintSelector>,
+    
+    #[arg(long, help = "상위 티켓 아래에 하위 작업을 트리로 표시")]
+    pub tree: bool,
+    
+    #[arg(long, help = "선행/후속 티켓(blocks 링크) 의존 관계 표시")]
+    pub deps: bool,
+    
+    #[arg(short, long, help = "인터랙티브 모드 (기본값: true)")]
+    pub interactive: Option<bool>,
+}
+
+struct SavedQuery {
+    name: String,
+    jql: Jql,
+    fields: Vec<String>,
+}
+
+async fn find_saved_query(context: &AppContext, name: &str) -> AppResult<SavedQuery> {
+    if let Some(filter) = context.config().filters.get(name) {
+        return Ok(SavedQuery {
+            name: name.to_string(),
+            jql: filter.to_jql(),
+            fields: filter.fields.clone(),
+        });
+    }
+    
+    let favourites = context.jira_client()?.get_favourite_filters().await?;
+    
+    favourites
+        .into_iter()
+        .find(|filter| filter.name.to_lowercase() == name.to_lowercase())
+        .and_then(|filter| filter.jql.map(|jql| SavedQuery { name: filter.name, jql: Jql::from_raw(&jql), fields: Vec::new() }))
+        .ok_or_else(|| AppError::not_found(format!("필터 '{}'를 jgf.json 또는 Jira 즐겨찾기에서 찾을 수 없습니다", name)))
+}
+
+async fn select_favourite_filter(context: &AppContext, interactive: bool) -> AppResult<Option<SavedQuery>> {
+    let spinner = utils::create_spinner("Jira 즐겨찾기 필터를 조회하고 있습니다...");
+    let favourites = context.jira_client()?.get_favourite_filters().await;
+    spinner.finish_and_clear();
+    let favourites: Vec<_> = favourites?
+        .into_iter()
+        .filter(|filter| filter.jql.is_some())
+        .collect();
+    
+    if favourites.is_empty() {
+        utils::warning_message("즐겨찾기 필터가 없습니다.");
+        return Ok(None);
+    }
+    
+    for filter in &favourites {
+        println!("{} {}", format!("[{}]", filter.id).bold(), filter.name.bold().cyan());
+        println!("   JQL: {}", filter.jql.as_deref().unwrap_or_default());
+        if let Some(ref url) = filter.view_url {
+            println!("   링크: {}", url.dimmed());
+        }
+        println!();
+    }
+    
+    if !interactive || !utils::prompt_confirmation("필터를 선택하여 티켓을 조회하시겠습니까?")? {
+        return Ok(None);
+    }
+    
+    let names: Vec<String> = favourites.iter().map(|filter| filter.name.clone()).collect();
+    let selected = utils::prompt_select("조회할 필터를 선택하세요:", names)?;
+    
+    Ok(favourites
+        .into_iter()
+        .find(|filter| filter.name == selected)
+        .and_then(|filter| filter.jql.map(|jql| SavedQuery { name: filter.name, jql: Jql::from_raw(&jql), fields: Vec::new() })))
+}
+
+pub async fn run(args: TicketsArgs) -> AppResult<()> {
     let config = Config::load()?;
     config.validate()?;
     
     let context = AppContext::new(config).init_clients().await?;
+    let limit = if args.all { None } else { Some(args.limit.unwrap_or(50)) };
+    let interactive = args.interactive.unwrap_or(true);
     
-    utils::info_message("할당된 티켓을 조회하고 있습니다...");
+    let saved_query = if args.favourites {
+        match select_favourite_filter(&context, interactive).await? {
+            Some(query) => Some(query),
+            None => return Ok(()),
+        }
+    } else if let Some(ref name) = args.filter {
+        Some(find_saved_query(&context, name).await?)
+    } else {
+        None
+    };
     
-    let issues = if let Some(status) = status_filter {
-        context.jira_client()?.search_issues_by_status(&status, limit).await?
+    let mut extra_fields: Vec<String> = Vec::new();
+    
+    let issues = if let Some(query) = saved_query {
+        utils::info_message(&format!("필터 '{}'로 티켓을 조회하고 있습니다...", query.name));
+        extra_fields = query.fields;
+        context.jira_client()?.search_all_with_fields(&query.jql, &extra_fields, limit).await?
+    } else if let Some(ref selector) = args.sprint {
+        let board_ids = context.board_ids().await?;
+        let sprint = context.jira_client()?.find_sprint(&board_ids, selector).await?
+            .ok_or_else(|| AppError::not_found(format!("스프린트를 찾을 수 없습니다: {}", selector)))?;
+        
+        utils::info_message(&format!("스프린트 '{}' ({}) 티켓을 조회하고 있습니다...", sprint.name, sprint.format_period()));
+        if let Some(ref goal) = sprint.goal.as_ref().filter(|goal| !goal.is_empty()) {
+            utils::info_message(&format!("스프린트 목표: {}", goal));
+        }
+        
+        let mut jql = Jql::with(Clause::eq("project", &context.config().jira_project))
+            .and(Clause::eq("sprint", sprint.id as i64));
+        if let Some(ref status) = args.status {
+            jql = jql.and(Clause::eq("status", status));
+        }
+        
+        context.jira_client()?.search_all(&jql.order_by("rank", SortOrder::Asc), limit).await?
+    } else if let Some(ref jql) = args.jql {
+        utils::info_message("JQL로 티켓을 조회하고 있습니다...");
+        context.jira_client()?.search_all(&Jql::from_raw(jql), limit).await?
+    } else if let Some(ref status) = args.status {
+        utils::info_message("할당된 티켓을 조회하고 있습니다...");
+        context.issue_tracker()?.search_issues_by_status(status, limit).await?
     } else {
-        context.jira_client()?.search_assigned_issues(&context.config().jira_username, limit).await?
+        utils::info_message("할당된 티켓을 조회하고 있습니다...");
+        context.issue_tracker()?.search_assigned_issues(limit).await?
     };
     
+    show_issues(&context, &issues, &extra_fields, args.tree, args.deps, interactive).await
+}
+
+fn format_status(context: &AppContext, status: &Status) -> colored::ColoredString {
+    let workflow = &context.config().workflow;
+    let category = status.status_category.as_ref().map(|c| c.key.as_str());
+    
+    if workflow.is_in_stage(status, WorkflowStage::Done) {
+        status.name.green()
+    } else if workflow.is_in_stage(status, WorkflowStage::Review) {
+        status.name.purple()
+    } else if workflow.is_in_stage(status, WorkflowStage::Start) {
+        status.name.yellow()
+    } else if category == Some("new") {
+        status.name.blue()
+    } else {
+        status.name.normal()
+    }
+}
+
+fn print_dependencies(context: &AppContext, issue: &Issue, indent: &str) {
+    for blocker in issue.blockers() {
+        let status = blocker.status().map(|status| format_status(context, status)).unwrap_or_default();
+        let marker = if blocker.is_done() { "✔".green() } else { "⛔".red() };
+        println!("{}   {} 선행: {} {} {}", indent, marker, blocker.key.cyan(), blocker.summary(), status);
+    }
+    for blocked in issue.blocked_issues() {
+        let status = blocked.status().map(|status| format_status(context, status)).unwrap_or_default();
+        println!("{}   {} 후속: {} {} {}", indent, "→".dimmed(), blocked.key.cyan(), blocked.summary(), status);
+    }
+}
+
+fn print_issue(context: &AppContext, number: usize, issue: &Issue, extra_fields: &[String], show_deps: bool, is_child: bool) {
+    let (prefix, indent) = if is_child { ("  └─ ", "     ") } else { ("", "") };
+    let number = format!("[{}]", number);
+    let key = issue.key.bold().cyan();
+    let status = format_status(context, &issue.fields.status);
+    let assignee = issue.format_assignee();
+    let priority = issue.format_priority();
+    
+    println!("{}{} {} {}", prefix, number.bold(), key, issue.fields.summary);
+    println!("{}   상태: {} | 담당자: {} | 우선순위: {}", indent, status, assignee, priority);
+    
+    for field in extra_fields {
+        println!("{}   {}: {}", indent, field, issue.format_field(field));
+    }
+    
+    if show_deps {
+        print_dependencies(context, issue, indent);
+    }
+    
+    let url = context.config().get_ticket_url(&issue.key);
+    println!("{}   링크: {}", indent, url.dimmed());
+    println!();
+}
+
+// 조회 결과에 없는 상위 티켓/하위 작업은 parent, subtasks 필드의 요약 정보로 표시합니다
+fn print_issue_tree(context: &AppContext, issues: &[Issue], extra_fields: &[String], show_deps: bool) {
+    let position = |key: &str| issues.iter().position(|issue| issue.key == key);
+    let mut orphan_parents: Vec<&str> = Vec::new();
+    
+    for (index, issue) in issues.iter().enumerate() {
+        match issue.parent_key() {
+            Some(parent_key) if position(parent_key).is_some() => continue,
+            Some(parent_key) => {
+                if orphan_parents.contains(&parent_key) {
+                    continue;
+                }
+                orphan_parents.push(parent_key);
+                
+                let parent = issue.fields.parent.as_ref().unwrap();
+                let status = parent.status().map(|status| format_status(context, status)).unwrap_or_default();
+                println!("{} {} {}", parent.key.bold().dimmed(), parent.summary().dimmed(), status);
+                println!();
+                
+                for (child_index, child) in issues.iter().enumerate() {
+                    if child.parent_key() == Some(parent_key) {
+                        print_issue(context, child_index + 1, child, extra_fields, show_deps, true);
+                    }
+                }
+            }
+            None => {
+                print_issue(context, index + 1, issue, extra_fields, show_deps, false);
+                
+                for subtask in &issue.fields.subtasks {
+                    match position(&subtask.key) {
+                        Some(child_index) => print_issue(context, child_index + 1, &issues[child_index], extra_fields, show_deps, true),
+                        None => {
+                            let status = subtask.status().map(|status| format_status(context, status)).unwrap_or_default();
+                            println!("  └─ {} {} {}", subtask.key.cyan(), subtask.summary(), status);
+                            println!();
+                        }
+                    }
+                }
+                
+                // subtasks 필드에 없지만 조회 결과에서 이 티켓을 상위로 가진 티켓
+                for (child_index, child) in issues.iter().enumerate() {
+                    let listed = issue.fields.subtasks.iter().any(|subtask| subtask.key == child.key);
+                    if child.parent_key() == Some(issue.key.as_str()) && !listed {
+                        print_issue(context, child_index + 1, child, extra_fields, show_deps, true);
+                    }
+                }
+            }
+        }
+    }
+}
+
+async fn show_issues(
+    context: &AppContext,
+    issues: &SearchResults,
+    extra_fields: &[String],
+    as_tree: bool,
+    show_deps: bool,
+    is_interactive: bool,
+) -> AppResult<()> {
     if issues.issues.is_empty() {
         utils::warning_message("조회된 티켓이 없습니다.");
         return Ok(());
     }
     
     utils::success_message(&format!("총 {} 개의 티켓을 찾았습니다.", issues.issues.len()));
+    if !issues.is_last {
+        utils::info_message("더 많은 티켓이 있습니다. 전체를 보려면 --all 옵션을 사용하세요.");
+    }
     println!();
     
-    for (index, issue) in issues.issues.iter().enumerate() {
-        let number = format!("[{}]", index + 1);
-        let key = format!("{}", issue.key).bold().cyan();
-        let summary = &issue.fields.summary;
-        let status = match issue.fields.status.name.as_str() {
-            "To Do" => issue.fields.status.name.blue(),
-            "In Progress" => issue.fields.status.name.yellow(),
-            "Done" => issue.fields.status.name.green(),
-            "In Review" => issue.fields.status.name.purple(),
-            _ => issue.fields.status.name.normal(),
-        };
-        let assignee = issue.format_assignee();
-        let priority = issue.format_priority();
-        
-        println!("{} {} {}", number.bold(), key, summary);
-        println!("   상태: {} | 담당자: {} | 우선순위: {}", status, assignee, priority);
-        
-        let url = context.config().get_jira_ticket_url(&issue.key);
-        println!("   링크: {}", url.dimmed());
-        println!();
+    if as_tree {
+        print_issue_tree(context, &issues.issues, extra_fields, show_deps);
+    } else {
+        for (index, issue) in issues.issues.iter().enumerate() {
+            print_issue(context, index + 1, issue, extra_fields, show_deps, false);
+        }
     }
     
-    let is_interactive = interactive.unwrap_or(true);
-    
     if !issues.issues.is_empty() && is_interactive {
         println!();
         let should_select = utils::prompt_confirmation("티켓을 선택하여 작업을 시작하시겠습니까?")?;
@@ -71,6 +307,7 @@ pub async fn run(limit: Option<i32>, status_filter: Option<String>, interactive:
             
             let actions = vec![
                 TicketAction::CreateBranch,
+                TicketAction::ShowDetails,
                 TicketAction::OpenBrowser,
                 TicketAction::Cancel,
             ];
@@ -79,10 +316,13 @@ pub async fn run(limit: Option<i32>, status_filter: Option<String>, interactive:
             
             match selected_action {
                 TicketAction::CreateBranch => {
-                    crate::cli::commands::start::run(selected_issue.key.clone()).await?;
+                    crate::cli::commands::start::run(selected_issue.key.clone(), false).await?;
+                }
+                TicketAction::ShowDetails => {
+                    crate::cli::commands::show::run(Some(selected_issue.key.clone()), false).await?;
                 }
                 TicketAction::OpenBrowser => {
-                    let url = context.config().get_jira_ticket_url(&selected_issue.key);
+                    let url = context.config().get_ticket_url(&selected_issue.key);
                     utils::info_message(&format!("브라우저에서 열기: {}", url));
                     
                     #[cfg(target_os = "macos")]
@@ -0,0 +1,157 @@
+use crate::{AppError, AppErrorType, AppResult, AppContext, Config, git::GitOps, utils};
+use crate::cli::workflow;
+use crate::jira::{TransitionTarget, WorkflowStage};
+use colored::Colorize;
+
+enum Outcome {
+    Moved(String),
+    AlreadyThere(String),
+    Cancelled,
+    Failed(String),
+}
+
+// start/review/done은 jgf.json workflow 매핑을 따르고, 그 외에는 상태 또는 전환 이름으로 찾습니다
+fn parse_target(value: &str) -> TransitionTarget {
+    match value.trim().to_lowercase().as_str() {
+        "start" => TransitionTarget::Stage(WorkflowStage::Start),
+        "review" => TransitionTarget::Stage(WorkflowStage::Review),
+        "done" => TransitionTarget::Stage(WorkflowStage::Done),
+        _ => TransitionTarget::Status(value.trim().to_string()),
+    }
+}
+
+async fn select_target(context: &AppContext, ticket: &str) -> AppResult<TransitionTarget> {
+    let spinner = utils::create_spinner(&format!("티켓 {}의 가능한 상태 전환을 조회하고 있습니다...", ticket));
+    let transitions = context.issue_tracker()?.get_transitions(ticket).await;
+    spinner.finish_and_clear();
+    let transitions = transitions?;
+    
+    if transitions.is_empty() {
+        return Err(AppError::not_found(format!("티켓 {}에서 가능한 상태 전환이 없습니다", ticket)));
+    }
+    
+    let workflow_config = &context.config().workflow;
+    let mut targets: Vec<TransitionTarget> = transitions.into_iter().map(TransitionTarget::Transition).collect();
+    let labels: Vec<String> = targets.iter().map(|target| target.label(workflow_config)).collect();
+    // 이름이 같은 전환이 여러 개면 id를 붙여 구분합니다
+    let options: Vec<String> = labels
+        .iter()
+        .zip(&targets)
+        .map(|(label, target)| match target {
+            TransitionTarget::Transition(transition) if labels.iter().filter(|other| *other == label).count() > 1 => {
+                format!("{} (#{})", label, transition.id)
+            }
+            _ => label.clone(),
+        })
+        .collect();
+    
+    let selected = utils::prompt_select("변경할 상태를 선택하세요:", options.clone())?;
+    let index = options
+        .iter()
+        .position(|option| *option == selected)
+        .ok_or_else(|| AppError::validation_error("선택한 상태 전환을 찾을 수 없습니다"))?;
+    
+    Ok(targets.swap_remove(index))
+}
+
+async fn move_ticket(context: &AppContext, ticket: &str, target: &TransitionTarget) -> Outcome {
+    let workflow_config = &context.config().workflow;
+    
+    let issue = match context.issue_tracker() {
+        Ok(tracker) => tracker.get_issue(ticket).await,
+        Err(e) => Err(e),
+    };
+    let issue = match issue {
+        Ok(issue) => issue,
+        Err(e) => return Outcome::Failed(e.to_string()),
+    };
+    
+    let status = &issue.fields.status;
+    let already_there = match target {
+        TransitionTarget::Stage(stage) => workflow_config.is_in_stage(status, *stage),
+        TransitionTarget::Status(name) => status.name.eq_ignore_ascii_case(name),
+        TransitionTarget::Transition(selected) => status.id == selected.to.id,
+    };
+    if already_there {
+        return Outcome::AlreadyThere(status.name.clone());
+    }
+    
+    match workflow::move_issue(context, ticket, target.clone()).await {
+        Ok(true) => Outcome::Moved(format!("{} → {}", status.name, target.label(workflow_config))),
+        Ok(false) => Outcome::Cancelled,
+        Err(e) => Outcome::Failed(e.to_string()),
+    }
+}
+
+pub async fn run(tickets: Vec<String>, to: Option<String>) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let mut keys: Vec<String> = Vec::new();
+    for ticket in &tickets {
+        let key = utils::format_ticket_key(&config, ticket.trim());
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    
+    if keys.is_empty() {
+        let key = GitOps::open()?
+            .get_current_ticket_key(&config.ticket_key_parser()?)?
+            .ok_or_else(|| AppError::validation_error("현재 브랜치에서 티켓 번호를 찾을 수 없습니다. 티켓 번호를 지정해주세요"))?;
+        keys.push(key);
+    }
+    
+    let context = AppContext::new(config).init_clients().await?;
+    
+    let target = match to {
+        Some(ref to) => parse_target(to),
+        None => select_target(&context, &keys[0]).await?,
+    };
+    let label = target.label(&context.config().workflow);
+    
+    if keys.len() > 1 {
+        utils::ticket_message(&format!("대상 티켓: {}", keys.join(", ")));
+        if !utils::prompt_confirmation(&format!("{}개 티켓을 '{}' 상태로 변경하시겠습니까?", keys.len(), label))? {
+            utils::info_message("상태 변경을 취소했습니다");
+            return Ok(());
+        }
+    }
+    
+    // 목록에서 고른 전환은 첫 티켓의 전환이므로, 나머지 티켓은 같은 목표 상태로 가는 경로를 찾습니다
+    let other_target = match &target {
+        TransitionTarget::Transition(selected) => TransitionTarget::Status(selected.to.name.clone()),
+        target => target.clone(),
+    };
+    
+    let mut outcomes = Vec::new();
+    for (index, key) in keys.iter().enumerate() {
+        let target = if index == 0 { &target } else { &other_target };
+        let outcome = move_ticket(&context, key, target).await;
+        outcomes.push((key, outcome));
+    }
+    
+    println!();
+    utils::sparkle_message(&format!("'{}' 상태 변경 결과", label));
+    
+    for (key, outcome) in &outcomes {
+        let key = key.bold().cyan();
+        match outcome {
+            Outcome::Moved(change) => println!("   {} {} {}", "✔".green(), key, change),
+            Outcome::AlreadyThere(status) => println!("   {} {} 이미 '{}' 상태", "-".dimmed(), key, status),
+            Outcome::Cancelled => println!("   {} {} 취소됨", "-".dimmed(), key),
+            Outcome::Failed(reason) => println!("   {} {} {}", "✘".red(), key, reason.red()),
+        }
+    }
+    
+    let failed = outcomes.iter().filter(|(_, outcome)| matches!(outcome, Outcome::Failed(_))).count();
+    let moved = outcomes.iter().filter(|(_, outcome)| matches!(outcome, Outcome::Moved(_))).count();
+    
+    println!();
+    if failed > 0 {
+        return Err(AppErrorType::Unknown(format!("{}개 성공, {}개 티켓 상태 변경 실패", moved, failed)).into());
+    }
+    
+    utils::success_message(&format!("{}개 티켓 상태 변경 완료", moved));
+    Ok(())
+}
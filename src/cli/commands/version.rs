@@ -0,0 +1,231 @@
+use crate::{AppError, AppErrorType, AppResult, AppContext, Config, git::GitOps, utils};
+use crate::jira::{IssueUpdate, JiraClient, TicketKeyParser, Version};
+use chrono::Local;
+use clap::Subcommand;
+use colored::Colorize;
+
+#[derive(Subcommand, Debug)]
+pub enum VersionCommand {
+    #[command(about = "프로젝트에 새 버전 생성")]
+    Create {
+        #[arg(help = "버전 이름 (예: 1.2.0)")]
+        name: String,
+        
+        #[arg(short, long, help = "버전 설명")]
+        description: Option<String>,
+        
+        #[arg(long, help = "릴리스 예정일 (YYYY-MM-DD)")]
+        date: Option<String>,
+    },
+    
+    #[command(about = "티켓 또는 Git 커밋 범위의 티켓에 Fix Version 지정")]
+    Assign {
+        #[arg(help = "버전 이름")]
+        name: String,
+        
+        #[arg(required = true, help = "티켓 번호 또는 커밋 범위 (예: EM-100 EM-101, v1.1.0..HEAD)")]
+        targets: Vec<String>,
+    },
+    
+    #[command(about = "버전을 릴리스됨으로 표시")]
+    Release {
+        #[arg(help = "버전 이름")]
+        name: String,
+        
+        #[arg(long, help = "릴리스 날짜 (YYYY-MM-DD, 기본값: 오늘)")]
+        date: Option<String>,
+    },
+}
+
+enum Outcome {
+    Assigned,
+    AlreadyAssigned,
+    Failed(String),
+}
+
+fn validate_date(date: &str) -> AppResult<()> {
+    chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map(|_| ())
+        .map_err(|_| AppError::validation_error(format!("날짜 형식이 올바르지 않습니다: {} (예: 2024-01-31)", date)))
+}
+
+// '..'이 있거나 티켓 번호로 보이지 않는 값은 Git 범위(ref..HEAD)로 해석합니다
+fn collect_ticket_keys(config: &Config, parser: &TicketKeyParser, targets: &[String]) -> AppResult<Vec<String>> {
+    let mut keys: Vec<String> = Vec::new();
+    let mut git_ops: Option<GitOps> = None;
+    
+    for target in targets {
+        let target = target.trim();
+        let is_ticket = !target.contains("..")
+            && (target.chars().all(|c| c.is_ascii_digit())
+                || parser.extract(target).is_some_and(|key| key.eq_ignore_ascii_case(target)));
+        
+        let found = if is_ticket {
+            vec![utils::format_ticket_key(config, target)]
+        } else {
+            if git_ops.is_none() {
+                git_ops = Some(GitOps::open()?);
+            }
+            let found = git_ops.as_ref().unwrap().ticket_keys_in_range(parser, target)?;
+            if found.is_empty() {
+                utils::warning_message(&format!("'{}' 범위의 커밋에서 티켓 번호를 찾을 수 없습니다", target));
+            }
+            found
+        };
+        
+        for key in found {
+            if !keys.contains(&key) {
+                keys.push(key);
+            }
+        }
+    }
+    
+    Ok(keys)
+}
+
+async fn require_version(jira_client: &JiraClient, name: &str) -> AppResult<Version> {
+    jira_client
+        .find_version(name)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("버전 '{}'을(를) 찾을 수 없습니다. 'jgf version create {}'로 먼저 생성해주세요", name, name)))
+}
+
+async fn create(name: String, description: Option<String>, date: Option<String>) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    if let Some(date) = &date {
+        validate_date(date)?;
+    }
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let jira_client = context.jira_client()?;
+    
+    if jira_client.find_version(&name).await?.is_some() {
+        return Err(AppError::validation_error(format!("버전 '{}'이(가) 이미 존재합니다", name)));
+    }
+    
+    let spinner = utils::create_spinner(&format!("버전 '{}'을(를) 생성하고 있습니다...", name));
+    let version = jira_client.create_version(&name, description.as_deref(), date.as_deref()).await;
+    spinner.finish_and_clear();
+    let version = version?;
+    
+    utils::package_message(&format!("버전 '{}' 생성 완료 ({})", version.name, version.format_state()));
+    Ok(())
+}
+
+async fn assign(name: String, targets: Vec<String>) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let keys = collect_ticket_keys(&config, &config.ticket_key_parser()?, &targets)?;
+    if keys.is_empty() {
+        return Err(AppError::not_found("버전을 지정할 티켓이 없습니다"));
+    }
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let jira_client = context.jira_client()?;
+    
+    let version = require_version(jira_client, &name).await?;
+    if version.released {
+        utils::warning_message(&format!("버전 '{}'은(는) 이미 릴리스되었습니다", version.name));
+    }
+    
+    let spinner = utils::create_spinner(&format!("{}개 티켓 정보를 Jira에서 조회하고 있습니다...", keys.len()));
+    let issues = jira_client.get_issues(&keys).await;
+    spinner.finish_and_clear();
+    let issues = issues?;
+    
+    utils::ticket_message(&format!("대상 티켓: {}", keys.join(", ")));
+    if !utils::prompt_confirmation(&format!("{}개 티켓에 Fix Version '{}'을(를) 지정하시겠습니까?", keys.len(), version.name))? {
+        utils::info_message("버전 지정을 취소했습니다");
+        return Ok(());
+    }
+    
+    let mut outcomes = Vec::new();
+    for key in &keys {
+        let outcome = match issues.iter().find(|issue| &issue.key == key) {
+            None => Outcome::Failed("Jira에서 찾을 수 없습니다".to_string()),
+            Some(issue) if issue.fields.fix_versions.iter().any(|v| v.name == version.name) => Outcome::AlreadyAssigned,
+            Some(_) => match jira_client.update_issue(key, &IssueUpdate::default().add_fix_version(&version.name)).await {
+                Ok(()) => Outcome::Assigned,
+                Err(e) => Outcome::Failed(e.to_string()),
+            },
+        };
+        outcomes.push((key, outcome));
+    }
+    
+    println!();
+    utils::sparkle_message(&format!("Fix Version '{}' 지정 결과", version.name));
+    
+    for (key, outcome) in &outcomes {
+        let key = key.bold().cyan();
+        match outcome {
+            Outcome::Assigned => println!("   {} {}", "✔".green(), key),
+            Outcome::AlreadyAssigned => println!("   {} {} 이미 지정됨", "-".dimmed(), key),
+            Outcome::Failed(reason) => println!("   {} {} {}", "✘".red(), key, reason.red()),
+        }
+    }
+    
+    let failed = outcomes.iter().filter(|(_, outcome)| matches!(outcome, Outcome::Failed(_))).count();
+    let assigned = outcomes.iter().filter(|(_, outcome)| matches!(outcome, Outcome::Assigned)).count();
+    
+    println!();
+    if failed > 0 {
+        return Err(AppErrorType::Unknown(format!("{}개 성공, {}개 티켓 버전 지정 실패", assigned, failed)).into());
+    }
+    
+    utils::success_message(&format!("{}개 티켓에 버전 지정 완료", assigned));
+    Ok(())
+}
+
+async fn release(name: String, date: Option<String>) -> AppResult<()> {
+    let config = Config::load()?;
+    config.validate()?;
+    
+    let date = date.unwrap_or_else(|| Local::now().format("%Y-%m-%d").to_string());
+    validate_date(&date)?;
+    
+    let context = AppContext::new(config).init_clients().await?;
+    let jira_client = context.jira_client()?;
+    
+    let version = require_version(jira_client, &name).await?;
+    if version.released {
+        utils::info_message(&format!("버전 '{}'은(는) 이미 {}", version.name, version.format_state()));
+        return Ok(());
+    }
+    
+    let unresolved = jira_client.search_unresolved_in_version(&version.name).await?.issues;
+    if !unresolved.is_empty() {
+        utils::warning_message(&format!("완료되지 않은 티켓이 {}개 있습니다", unresolved.len()));
+        for issue in &unresolved {
+            println!("   {} {} ({})", issue.key.bold().cyan(), issue.fields.summary, issue.fields.status.name);
+        }
+    }
+    
+    if !utils::prompt_confirmation(&format!("버전 '{}'을(를) {} 날짜로 릴리스하시겠습니까?", version.name, date))? {
+        utils::info_message("릴리스를 취소했습니다");
+        return Ok(());
+    }
+    
+    let version_id = version
+        .id
+        .as_deref()
+        .ok_or_else(|| AppError::not_found(format!("버전 '{}'의 ID를 확인할 수 없습니다", version.name)))?;
+    
+    let spinner = utils::create_spinner(&format!("버전 '{}'을(를) 릴리스하고 있습니다...", version.name));
+    let released = jira_client.release_version(version_id, &date).await;
+    spinner.finish_and_clear();
+    let released = released?;
+    
+    utils::rocket_message(&format!("버전 '{}' 릴리스 완료 ({})", released.name, released.format_state()));
+    Ok(())
+}
+
+pub async fn run(command: VersionCommand) -> AppResult<()> {
+    match command {
+        VersionCommand::Create { name, description, date } => create(name, description, date).await,
+        VersionCommand::Assign { name, targets } => assign(name, targets).await,
+        VersionCommand::Release { name, date } => release(name, date).await,
+    }
+}
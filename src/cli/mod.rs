@@ -1,7 +1,14 @@
 pub mod commands;
+pub mod workflow;
+pub mod worklog;
 
 use clap::{Parser, Subcommand};
 use crate::AppResult;
+use std::path::PathBuf;
+use commands::create::CreateArgs;
+use commands::subtask::SubtaskCommand;
+use commands::tickets::TicketsArgs;
+use commands::version::VersionCommand;
 
 #[derive(Parser)]
 #[command(name = "jgf")]
@@ -18,27 +25,78 @@ pub enum Commands {
     Init,
     
     #[command(about = "할당된 티켓 목록 조회")]
-    Tickets {
-        #[arg(short, long, help = "표시할 최대 티켓 수")]
-        limit: Option<i32>,
-        
-        #[arg(short, long, help = "상태별 필터링 (예: In Progress, Done)")]
-        status: Option<String>,
-        
-        #[arg(short, long, help = "인터랙티브 모드 (기본값: true)")]
-        interactive: Option<bool>,
-    },
+    Tickets(TicketsArgs),
     
-    #[command(about = "티켓으로 브랜치 생성 및 In Progress 상태로 변경")]
+    #[command(about = "티켓으로 브랜치 생성 및 시작(workflow.start) 단계 상태로 변경")]
     Start {
         #[arg(help = "Jira 티켓 번호 (예: EM-100)")]
         ticket: String,
+        
+        #[arg(long, help = "스프린트에 속하지 않은 티켓을 현재 활성 스프린트로 이동")]
+        sprint: bool,
+    },
+    
+    #[command(about = "티켓 상세 정보 조회 (설명, 코멘트, 연결된 이슈, 브랜치/PR 등)")]
+    Show {
+        #[arg(help = "Jira 티켓 번호 (기본값: 현재 브랜치의 티켓)")]
+        ticket: Option<String>,
+        
+        #[arg(long, help = "모든 코멘트 표시 (기본값: 최근 5개)")]
+        all_comments: bool,
+    },
+    
+    #[command(about = "티켓 상태 변경 (여러 티켓 일괄 변경 가능)")]
+    Transition {
+        #[arg(help = "Jira 티켓 번호 (기본값: 현재 브랜치의 티켓)")]
+        tickets: Vec<String>,
+        
+        #[arg(long, help = "변경할 상태 또는 전환 이름 (start/review/done은 workflow 매핑 사용, 생략하면 선택)")]
+        to: Option<String>,
+    },
+    
+    #[command(about = "Jira 티켓에 작업 시간 기록")]
+    Log {
+        #[arg(help = "작업 시간 (예: 1h 30m, 2d, 45m / 생략하면 타이머 경과 시간 사용)")]
+        duration: Option<String>,
+        
+        #[arg(short, long, help = "작업 내용 (Markdown)")]
+        comment: Option<String>,
+        
+        #[arg(short, long, help = "Jira 티켓 번호 (기본값: 현재 브랜치의 티켓)")]
+        ticket: Option<String>,
+    },
+    
+    #[command(about = "Git 커밋 범위의 티켓으로 릴리스 노트(Markdown) 생성")]
+    ReleaseNotes {
+        #[arg(help = "커밋 범위 (예: v1.0.0..v1.1.0, v1.0.0..HEAD)")]
+        range: String,
+        
+        #[arg(short, long, help = "저장할 파일 경로 (생략하면 표준 출력)")]
+        output: Option<PathBuf>,
+        
+        #[arg(long, help = "릴리스 노트 템플릿 파일 (기본값: jgf.json의 releaseNotes)")]
+        template: Option<PathBuf>,
+    },
+    
+    #[command(about = "Jira 버전(Fix Version) 관리")]
+    Version {
+        #[command(subcommand)]
+        command: VersionCommand,
+    },
+    
+    #[command(about = "새 Jira 티켓 생성 (생성 후 바로 작업 시작 가능)")]
+    Create(CreateArgs),
+    
+    #[command(about = "하위 작업 관리")]
+    Subtask {
+        #[command(subcommand)]
+        command: SubtaskCommand,
     },
     
-    #[command(about = "현재 브랜치로 PR 생성 및 In Review 상태로 변경")]
+    #[command(about = "현재 브랜치로 PR 생성 및 리뷰(workflow.review) 단계 상태로 변경")]
     Pr,
     
-    #[command(about = "머지된 브랜치 확인 및 Done 상태로 변경")]
+    #[command(about = "머지된 브랜치 확인 및 완료(workflow.done) 단계 상태로 변경")]
     Sync,
 }
 
@@ -46,8 +104,15 @@ impl Cli {
     pub async fn run(self) -> AppResult<()> {
         match self.command {
             Some(Commands::Init) => commands::init::run().await,
-            Some(Commands::Tickets { limit, status, interactive }) => commands::tickets::run(limit, status, interactive).await,
-            Some(Commands::Start { ticket }) => commands::start::run(ticket).await,
+            Some(Commands::Tickets(args)) => commands::tickets::run(args).await,
+            Some(Commands::Start { ticket, sprint }) => commands::start::run(ticket, sprint).await,
+            Some(Commands::Show { ticket, all_comments }) => commands::show::run(ticket, all_comments).await,
+            Some(Commands::Transition { tickets, to }) => commands::transition::run(tickets, to).await,
+            Some(Commands::Log { duration, comment, ticket }) => commands::log::run(duration, comment, ticket).await,
+            Some(Commands::ReleaseNotes { range, output, template }) => commands::release_notes::run(range, output, template).await,
+            Some(Commands::Version { command }) => commands::version::run(command).await,
+            Some(Commands::Create(args)) => commands::create::run(args).await,
+            Some(Commands::Subtask { command }) => commands::subtask::run(command).await,
             Some(Commands::Pr) => commands::pr::run().await,
             Some(Commands::Sync) => commands::sync::run().await,
             None => {
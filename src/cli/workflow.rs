@@ -0,0 +1,83 @@
+use crate::{AppError, AppResult, AppContext, utils};
+use crate::jira::{adf, JiraFlavor, Transition, TransitionField, TransitionTarget};
+use serde_json::{json, Value};
+
+// 전환 화면의 필수 필드 중 jgf.json 기본값이 없는 필드를 입력받습니다
+fn prompt_field_value(transition: &Transition, field: &TransitionField, flavor: JiraFlavor) -> AppResult<Value> {
+    let message = format!("'{}' 전환에 필요한 '{}' 값을 입력하세요:", transition.name, field.name);
+    let field_type = field.schema
+        .as_ref()
+        .map(|schema| schema.field_type.as_str())
+        .unwrap_or("string");
+    
+    if !field.allowed_values.is_empty() {
+        let options: Vec<String> = field.allowed_values
+            .iter()
+            .map(TransitionField::allowed_value_label)
+            .collect();
+        let selected = utils::prompt_select(&message, options.clone())?;
+        let index = options.iter().position(|option| option == &selected).unwrap_or(0);
+        
+        let value = &field.allowed_values[index];
+        let reference = match value.get("id") {
+            Some(id) => json!({ "id": id }),
+            None => value.clone(),
+        };
+        
+        return Ok(if field_type == "array" { json!([reference]) } else { reference });
+    }
+    
+    let text = utils::prompt_text(&message, None)?;
+    let is_rich_text = field.schema
+        .as_ref()
+        .and_then(|schema| schema.custom.as_deref())
+        .is_some_and(|custom| custom.ends_with(":textarea"));
+    
+    match field_type {
+        "number" => text.trim()
+            .parse::<f64>()
+            .map(|number| json!(number))
+            .map_err(|_| AppError::validation_error(format!("'{}'은 숫자여야 합니다", field.name))),
+        "array" => Ok(json!(text.split(',').map(str::trim).filter(|v| !v.is_empty()).collect::<Vec<_>>())),
+        // Cloud는 accountId, Server는 사용자 이름으로 사용자를 지정합니다
+        "user" if flavor.is_cloud() => Ok(json!({ "accountId": text.trim() })),
+        "user" => Ok(json!({ "name": text.trim() })),
+        _ if is_rich_text => Ok(adf::from_markdown(&text)),
+        _ => Ok(Value::String(text)),
+    }
+}
+
+// 전환 경로를 찾아 여러 단계가 필요하면 미리보기 후 확인을 받고 적용합니다
+pub async fn move_issue(context: &AppContext, issue_key: &str, target: TransitionTarget) -> AppResult<bool> {
+    let tracker = context.issue_tracker()?;
+    let workflow = &context.config().workflow;
+    let label = target.label(workflow);
+    
+    let spinner = utils::create_spinner(&format!("'{}' 상태로의 전환 경로를 찾는 중...", label));
+    let path = tracker.find_transition_path(issue_key, workflow, &target).await;
+    spinner.finish_and_clear();
+    let path = path?;
+    
+    if path.is_multi_step() {
+        utils::info_message(&format!("{}단계 전환이 필요합니다: {}", path.steps.len(), path.preview()));
+        
+        if !utils::prompt_confirmation("이 경로로 상태를 변경하시겠습니까?")? {
+            return Ok(false);
+        }
+    }
+    
+    let mut inputs = path.default_inputs(workflow);
+    for (step, input) in path.steps.iter().zip(inputs.iter_mut()) {
+        for (field_id, field) in input.missing_required(step) {
+            let value = prompt_field_value(step, field, context.config().jira_flavor)?;
+            input.set_value(field_id, value);
+        }
+    }
+    
+    let spinner = utils::create_spinner(&format!("티켓 {} 상태를 '{}'로 변경 중...", issue_key, label));
+    let result = tracker.apply_transition_path(issue_key, &path, &inputs).await;
+    spinner.finish_and_clear();
+    result?;
+    
+    Ok(true)
+}
@@ -0,0 +1,90 @@
+use crate::{AppError, AppResult, AppContext, utils};
+use crate::jira::worklog::{format_duration, parse_duration};
+use crate::timer::TimerStore;
+use chrono::{DateTime, Utc};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TimerFinish {
+    // PR 생성 후에도 리뷰 반영 작업이 이어지므로 기록한 시점부터 다시 측정
+    Restart,
+    Clear,
+}
+
+pub fn prompt_duration(default: Option<&str>) -> AppResult<u64> {
+    let text = utils::prompt_text("기록할 작업 시간 (예: 1h 30m):", default)?;
+    parse_duration(&text)
+        .ok_or_else(|| AppError::validation_error(format!("작업 시간 형식이 올바르지 않습니다: {} (예: 1h 30m, 2d, 45m / 최대 1w)", text)))
+}
+
+pub async fn log_work(
+    context: &AppContext,
+    ticket: &str,
+    seconds: u64,
+    started: DateTime<Utc>,
+    comment: Option<&str>,
+) -> AppResult<()> {
+    let duration = format_duration(seconds);
+    
+    let spinner = utils::create_spinner(&format!("티켓 {}에 작업 시간 {} 기록 중...", ticket, duration));
+    let result = context.jira_client()?.add_worklog(ticket, seconds, started, comment).await;
+    spinner.finish_and_clear();
+    result?;
+    
+    utils::success_message(&format!("티켓 {}에 작업 시간 {}을 기록했습니다", ticket, duration));
+    Ok(())
+}
+
+// 작업 시간 기록은 Jira에서만 지원합니다
+fn timer_enabled(context: &AppContext) -> bool {
+    context.config().worklog.timer && context.config().issue_tracker.is_jira()
+}
+
+pub fn start_timer(context: &AppContext, ticket: &str) {
+    if !timer_enabled(context) {
+        return;
+    }
+    
+    let store = TimerStore::new(context.config());
+    match store.get(ticket) {
+        Ok(Some(timer)) => {
+            let elapsed = format_duration(timer.elapsed_seconds());
+            utils::info_message(&format!("⏱ 작업 시간 측정 중입니다 (경과: {})", elapsed));
+        }
+        Ok(None) => match store.start(ticket) {
+            Ok(_) => utils::info_message("⏱ 작업 시간 측정을 시작합니다"),
+            Err(e) => utils::warning_message(&format!("타이머 시작 실패: {}", e)),
+        },
+        Err(e) => utils::warning_message(&format!("타이머 조회 실패: {}", e)),
+    }
+}
+
+// 타이머가 있으면 경과 시간으로 채운 작업 시간 기록을 제안합니다
+pub async fn offer_timer_worklog(context: &AppContext, ticket: &str, finish: TimerFinish) -> AppResult<()> {
+    if !timer_enabled(context) {
+        return Ok(());
+    }
+    
+    let store = TimerStore::new(context.config());
+    let Some(timer) = store.get(ticket)? else {
+        return Ok(());
+    };
+    
+    let elapsed = format_duration(timer.elapsed_seconds());
+    let should_log = utils::prompt_confirmation(&format!("티켓 {}의 작업 시간 {}을 Jira에 기록하시겠습니까?", ticket, elapsed))?;
+    
+    if should_log {
+        let seconds = prompt_duration(Some(&elapsed))?;
+        let comment = utils::prompt_text("작업 내용 (생략 가능):", Some(""))?;
+        log_work(context, ticket, seconds, timer.started_at, Some(&comment)).await?;
+    }
+    
+    match finish {
+        TimerFinish::Restart if should_log => {
+            store.start(ticket)?;
+        }
+        TimerFinish::Restart => {}
+        TimerFinish::Clear => store.clear(ticket)?,
+    }
+    
+    Ok(())
+}
@@ -0,0 +1,95 @@
+use crate::AppResult;
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum CodeHostKind {
+    #[default]
+    GitHub,
+    GitLab,
+    Bitbucket,
+}
+
+impl CodeHostKind {
+    pub fn is_github(&self) -> bool {
+        *self == CodeHostKind::GitHub
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeRequestState {
+    Open,
+    Closed,
+    Merged,
+}
+
+// GitHub PR, GitLab MR을 같은 형태로 다루기 위한 공통 모델
+#[derive(Debug, Clone)]
+pub struct ChangeRequest {
+    pub number: u64,
+    // 화면에 표시할 번호 (GitHub: #12, GitLab: !12)
+    pub reference: String,
+    pub title: String,
+    pub url: String,
+    pub source_branch: String,
+    pub target_branch: String,
+    pub state: ChangeRequestState,
+    // PR/MR에 포함된 마지막 커밋 (Bitbucket은 앞 12자리만 제공)
+    pub head_sha: Option<String>,
+}
+
+impl ChangeRequest {
+    pub fn is_merged(&self) -> bool {
+        self.state == ChangeRequestState::Merged
+    }
+
+    // 로컬 브랜치 끝 커밋이 PR/MR에 포함된 마지막 커밋과 같은지 확인합니다
+    pub fn contains_commit(&self, commit_sha: &str) -> bool {
+        self.head_sha
+            .as_deref()
+            .is_some_and(|head_sha| head_sha.len() >= 7 && commit_sha.starts_with(head_sha))
+    }
+
+    pub fn format_state(&self) -> &str {
+        match self.state {
+            ChangeRequestState::Open => "열림",
+            ChangeRequestState::Closed => "닫힘",
+            ChangeRequestState::Merged => "머지됨",
+        }
+    }
+}
+
+#[async_trait]
+pub trait CodeHost: Send + Sync {
+    // 메시지와 Jira 원격 링크에 표시할 이름 (예: GitHub)
+    fn name(&self) -> &'static str;
+
+    // PR 또는 MR
+    fn change_request_label(&self) -> &'static str;
+
+    // Pull Request 또는 Merge Request (Jira 원격 링크의 관계 이름)
+    fn change_request_name(&self) -> &'static str;
+
+    fn icon_url(&self) -> String;
+
+    // 브랜치의 PR/MR 목록을 볼 수 있는 웹 주소
+    fn branch_search_url(&self, branch: &str) -> String;
+
+    async fn create_change_request(
+        &self,
+        title: &str,
+        body: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> AppResult<ChangeRequest>;
+
+    // 닫히거나 머지된 것을 포함해 가장 최근의 PR/MR
+    async fn find_change_request(&self, source_branch: &str) -> AppResult<Option<ChangeRequest>>;
+
+    async fn get_change_request(&self, number: u64) -> AppResult<ChangeRequest>;
+
+    async fn merge_change_request(&self, number: u64) -> AppResult<()>;
+}
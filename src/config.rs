@@ -1,19 +1,124 @@
-use crate::error::{AppError, AppErrorType, AppResult, AppErrorExt};
+use crate::code_host::CodeHostKind;
+use crate::error::{AppError, AppResult};
+use crate::issue_tracker::IssueTrackerKind;
+use crate::jira::{JiraFlavor, Jql, TicketKeyParser, WorkflowConfig};
+use crate::utils;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::env;
 use colored::Colorize;
 
+const BRANCH_SLUG_MAX_LENGTH: usize = 40;
+
+const GITHUB_API_URL: &str = "https://api.github.com";
+const GITHUB_WEB_URL: &str = "https://github.com";
+const GITLAB_WEB_URL: &str = "https://gitlab.com";
+const GITHUB_ISSUE_PREFIX: &str = "GH";
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct ProjectConfig {
     pub project: String,
-    pub jira: JiraConfig,
-    pub github: GithubConfig,
+    // GitHub Issues를 이슈 트래커로 사용하면 생략할 수 있습니다
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub jira: Option<JiraConfig>,
+    // 티켓을 관리하는 이슈 트래커 (jira, github)
+    #[serde(default, skip_serializing_if = "IssueTrackerKind::is_jira")]
+    pub tracker: IssueTrackerKind,
+    // PR/MR을 만들 코드 호스트 (github, gitlab, bitbucket)
+    #[serde(default, skip_serializing_if = "CodeHostKind::is_github")]
+    pub host: CodeHostKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub github: Option<GithubConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gitlab: Option<GitlabConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bitbucket: Option<BitbucketConfig>,
     #[serde(rename = "defaultBranch")]
     pub default_branch: String,
     #[serde(rename = "prTemplate", skip_serializing_if = "Option::is_none")]
     pub pr_template: Option<PrTemplate>,
+    #[serde(rename = "branchTemplate", skip_serializing_if = "Option::is_none")]
+    pub branch_template: Option<String>,
+    #[serde(rename = "branchTypes", skip_serializing_if = "Option::is_none")]
+    pub branch_types: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workflow: Option<WorkflowConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<HashMap<String, FilterConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<StartConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub worklog: Option<WorklogConfig>,
+    #[serde(rename = "releaseNotes", skip_serializing_if = "Option::is_none")]
+    pub release_notes: Option<ReleaseNotesConfig>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct ReleaseNotesConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub item: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WorklogConfig {
+    // start 시 타이머를 시작하고 pr/sync 완료 시 작업 시간 기록을 제안합니다
+    #[serde(default)]
+    pub timer: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct StartConfig {
+    #[serde(rename = "assignSelf", default = "default_assign_self")]
+    pub assign_self: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub labels: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub components: Vec<String>,
+    // 완료되지 않은 선행 티켓(is blocked by)이 있으면 경고 대신 작업 시작을 거부합니다
+    #[serde(rename = "refuseBlocked", default)]
+    pub refuse_blocked: bool,
+}
+
+fn default_assign_self() -> bool {
+    true
+}
+
+impl Default for StartConfig {
+    fn default() -> Self {
+        Self {
+            assign_self: default_assign_self(),
+            labels: Vec::new(),
+            components: Vec::new(),
+            refuse_blocked: false,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct FilterConfig {
+    pub jql: String,
+    #[serde(rename = "orderBy", skip_serializing_if = "Option::is_none")]
+    pub order_by: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+}
+
+impl FilterConfig {
+    // orderBy가 지정되면 JQL에 있던 정렬 조건을 대체합니다
+    pub fn to_jql(&self) -> Jql {
+        let jql = Jql::from_raw(&self.jql);
+        
+        match &self.order_by {
+            Some(order_by) => jql.replace_order_by_raw(order_by),
+            None => jql,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,18 +129,74 @@ pub struct PrTemplate {
     pub content: Option<String>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct JiraConfig {
     pub url: String,
     pub project: String,
+    #[serde(default, skip_serializing_if = "JiraFlavor::is_cloud")]
+    pub flavor: JiraFlavor,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub username: Option<String>,
+    #[serde(rename = "ticketPattern", skip_serializing_if = "Option::is_none")]
+    pub ticket_pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub boards: Vec<u64>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct GithubConfig {
     pub owner: String,
     pub repo: String,
+    // GitHub Enterprise Server: 예) https://github.example.com/api/v3
+    #[serde(rename = "apiUrl", skip_serializing_if = "Option::is_none")]
+    pub api_url: Option<String>,
+    #[serde(rename = "webUrl", skip_serializing_if = "Option::is_none")]
+    pub web_url: Option<String>,
+    // tracker가 github일 때의 티켓 키 접두사 (기본값: GH → GH-12)
+    #[serde(rename = "issuePrefix", skip_serializing_if = "Option::is_none")]
+    pub issue_prefix: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct GitlabConfig {
+    // 예) group/subgroup/repo
+    pub project: String,
+    // 설치형 GitLab 주소 (기본값: https://gitlab.com)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct BitbucketConfig {
+    pub workspace: String,
+    // 저장소 slug (URL에 쓰이는 이름)
+    pub repo: String,
+    // 앱 비밀번호를 쓰는 경우의 Bitbucket 사용자명. 없으면 BITBUCKET_TOKEN을 액세스 토큰으로 사용합니다
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
+
+// 한쪽만 지정하면 GitHub Enterprise Server 규칙(<웹 주소>/api/v3)으로 나머지를 추정합니다
+fn resolve_github_urls(api_url: Option<String>, web_url: Option<String>) -> AppResult<(String, String)> {
+    let trim = |url: String| url.trim_end_matches('/').to_string();
+    
+    match (api_url.map(trim), web_url.map(trim)) {
+        (Some(api_url), Some(web_url)) => Ok((api_url, web_url)),
+        (Some(api_url), None) => {
+            let web_url = match api_url.strip_suffix("/api/v3") {
+                Some(web_url) => web_url.to_string(),
+                None if api_url == GITHUB_API_URL => GITHUB_WEB_URL.to_string(),
+                None => return Err(AppError::config_error(format!(
+                    "GitHub API 주소가 /api/v3로 끝나지 않아 웹 주소를 추정할 수 없습니다. webUrl(GITHUB_WEB_URL)을 함께 지정하세요: {}",
+                    api_url
+                ))),
+            };
+            Ok((api_url, web_url))
+        }
+        (None, Some(web_url)) if web_url == GITHUB_WEB_URL => Ok((GITHUB_API_URL.to_string(), web_url)),
+        (None, Some(web_url)) => Ok((format!("{}/api/v3", web_url), web_url)),
+        (None, None) => Ok((GITHUB_API_URL.to_string(), GITHUB_WEB_URL.to_string())),
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -44,8 +205,26 @@ pub struct Config {
     pub jira_project: String,
     pub jira_username: String,
     pub jira_token: String,
+    pub jira_flavor: JiraFlavor,
+    pub jira_ticket_pattern: Option<String>,
+    pub jira_boards: Vec<u64>,
+    
+    pub issue_tracker: IssueTrackerKind,
+    // 티켓 키 접두사 (Jira: 프로젝트 키, GitHub Issues: issuePrefix)
+    pub ticket_prefix: String,
     
     pub github_token: String,
+    pub github_api_url: String,
+    pub github_web_url: String,
+    
+    pub code_host: CodeHostKind,
+    pub gitlab_url: String,
+    pub gitlab_project: String,
+    pub gitlab_token: String,
+    pub bitbucket_workspace: String,
+    pub bitbucket_repo: String,
+    pub bitbucket_username: Option<String>,
+    pub bitbucket_token: String,
     pub repo_owner: String,
     pub repo_name: String,
     
@@ -55,6 +234,17 @@ pub struct Config {
     
     pub project_root: Option<PathBuf>,
     pub pr_template_content: Option<String>,
+    
+    pub branch_template: Option<String>,
+    pub branch_types: HashMap<String, String>,
+    
+    pub workflow: WorkflowConfig,
+    pub filters: HashMap<String, FilterConfig>,
+    pub start: StartConfig,
+    pub worklog: WorklogConfig,
+    pub release_notes_template: Option<String>,
+    pub release_notes_path: Option<PathBuf>,
+    pub release_notes_item: Option<String>,
 }
 
 impl Config {
@@ -93,7 +283,7 @@ impl Config {
         let content = fs::read_to_string(config_path)
             .map_err(|e| AppError::config_error(format!("설정 파일을 읽을 수 없습니다: {}", e)))?;
         
-        let mut project_config: ProjectConfig = serde_json::from_str(&content)
+        let project_config: ProjectConfig = serde_json::from_str(&content)
             .map_err(|e| AppError::config_error(format!("설정 파일 파싱 실패: {}", e)))?;
         
         if let Some(ref root) = project_root {
@@ -103,25 +293,65 @@ impl Config {
             }
         }
         
-        let jira_username = project_config.jira.username.unwrap_or_else(|| {
+        let tracker = project_config.tracker;
+        let jira = match project_config.jira {
+            Some(jira) => jira,
+            None if !tracker.is_jira() => JiraConfig::default(),
+            None => return Err(AppError::config_error("jgf.json에 jira 설정이 없습니다")),
+        };
+        
+        let jira_username = jira.username.unwrap_or_else(|| {
             env::var("JIRA_USERNAME").unwrap_or_default()
         });
         
-        let jira_token = env::var("JIRA_TOKEN")
-            .with_app_type(AppErrorType::ConfigError("JIRA_TOKEN이 .env 파일에 설정되지 않았습니다".into()))?;
+        // 사용하는 이슈 트래커와 코드 호스트의 토큰만 필수입니다
+        let host = project_config.host;
+        let host_token = |name: &str, required: bool| match env::var(name) {
+            Ok(token) => Ok(token),
+            Err(_) if !required => Ok(String::new()),
+            Err(_) => Err(AppError::config_error(format!("{}이 .env 파일에 설정되지 않았습니다", name))),
+        };
+        let jira_token = host_token("JIRA_TOKEN", tracker.is_jira())?;
+        let github_token = host_token("GITHUB_TOKEN", host == CodeHostKind::GitHub || tracker == IssueTrackerKind::GitHub)?;
+        let gitlab_token = host_token("GITLAB_TOKEN", host == CodeHostKind::GitLab)?;
+        let bitbucket_token = host_token("BITBUCKET_TOKEN", host == CodeHostKind::Bitbucket)?;
         
-        let github_token = env::var("GITHUB_TOKEN")
-            .with_app_type(AppErrorType::ConfigError("GITHUB_TOKEN이 .env 파일에 설정되지 않았습니다".into()))?;
+        let github = project_config.github.unwrap_or_default();
+        let gitlab = project_config.gitlab.unwrap_or_default();
+        let bitbucket = project_config.bitbucket.unwrap_or_default();
+        let (github_api_url, github_web_url) = resolve_github_urls(github.api_url, github.web_url)?;
+        
+        let ticket_prefix = match tracker {
+            IssueTrackerKind::Jira => jira.project.clone(),
+            IssueTrackerKind::GitHub => github.issue_prefix.unwrap_or_else(|| GITHUB_ISSUE_PREFIX.to_string()),
+        };
         
         let mut config = Config {
-            jira_url: project_config.jira.url,
-            jira_project: project_config.jira.project,
+            jira_url: jira.url,
+            jira_project: jira.project,
             jira_username,
             jira_token,
+            jira_flavor: jira.flavor,
+            jira_ticket_pattern: jira.ticket_pattern,
+            jira_boards: jira.boards,
+            
+            issue_tracker: tracker,
+            ticket_prefix,
             
             github_token,
-            repo_owner: project_config.github.owner,
-            repo_name: project_config.github.repo,
+            github_api_url,
+            github_web_url,
+            repo_owner: github.owner,
+            repo_name: github.repo,
+            
+            code_host: host,
+            gitlab_url: gitlab.url.unwrap_or_else(|| GITLAB_WEB_URL.to_string()).trim_end_matches('/').to_string(),
+            gitlab_project: gitlab.project,
+            gitlab_token,
+            bitbucket_workspace: bitbucket.workspace,
+            bitbucket_repo: bitbucket.repo,
+            bitbucket_username: bitbucket.username.or_else(|| env::var("BITBUCKET_USERNAME").ok()),
+            bitbucket_token,
             
             default_branch: project_config.default_branch,
             
@@ -129,16 +359,36 @@ impl Config {
             
             project_root,
             pr_template_content: None,
+            
+            branch_template: project_config.branch_template,
+            branch_types: project_config.branch_types.unwrap_or_default(),
+            
+            workflow: project_config.workflow.unwrap_or_default(),
+            filters: project_config.filters.unwrap_or_default(),
+            start: project_config.start.unwrap_or_default(),
+            worklog: project_config.worklog.unwrap_or_default(),
+            release_notes_template: None,
+            release_notes_path: None,
+            release_notes_item: None,
         };
         
+        if let Some(release_notes) = project_config.release_notes {
+            config.release_notes_item = release_notes.item;
+            // 파일은 release-notes 실행 시 읽어 경로가 잘못되었으면 그때 알려줍니다
+            config.release_notes_path = release_notes.path.map(|path| match &config.project_root {
+                Some(root) => root.join(path),
+                None => PathBuf::from(path),
+            });
+            config.release_notes_template = release_notes.content;
+        }
+        
         if let Some(template) = project_config.pr_template {
             if let Some(path) = template.path {
                 if let Some(root) = &config.project_root {
                     let template_path = root.join(&path);
-                    if template_path.exists() {
-                        if let Ok(content) = fs::read_to_string(&template_path) {
-                            config.pr_template_content = Some(content);
-                        }
+                    if template_path.exists()
+                        && let Ok(content) = fs::read_to_string(&template_path) {
+                        config.pr_template_content = Some(content);
                     }
                 }
             } else if let Some(content) = template.content {
@@ -152,22 +402,69 @@ impl Config {
     pub fn from_env() -> AppResult<Self> {
         dotenv::dotenv().ok();
         
+        let (github_api_url, github_web_url) = resolve_github_urls(
+            std::env::var("GITHUB_API_URL").ok(),
+            std::env::var("GITHUB_WEB_URL").ok(),
+        )?;
+        
+        let code_host: CodeHostKind = match std::env::var("CODE_HOST") {
+            Ok(host) => host.parse()
+                .map_err(|_| AppError::config_error(format!("CODE_HOST는 github, gitlab, bitbucket 중 하나여야 합니다: {}", host)))?,
+            Err(_) => CodeHostKind::default(),
+        };
+        let issue_tracker: IssueTrackerKind = match std::env::var("ISSUE_TRACKER") {
+            Ok(tracker) => tracker.parse()
+                .map_err(|_| AppError::config_error(format!("ISSUE_TRACKER는 jira 또는 github여야 합니다: {}", tracker)))?,
+            Err(_) => IssueTrackerKind::default(),
+        };
+        let required_var = |name: &str, required: bool| match std::env::var(name) {
+            Ok(value) => Ok(value),
+            Err(_) if !required => Ok(String::new()),
+            Err(_) => Err(AppError::config_error(format!("{}가 설정되지 않았습니다", name))),
+        };
+        let jira_var = |name: &str| required_var(name, issue_tracker.is_jira());
+        let jira_flavor: JiraFlavor = match std::env::var("JIRA_FLAVOR") {
+            Ok(flavor) => flavor.parse()
+                .map_err(|_| AppError::config_error(format!("JIRA_FLAVOR는 cloud 또는 server여야 합니다: {}", flavor)))?,
+            Err(_) => JiraFlavor::default(),
+        };
+        let github_var = |name: &str| required_var(name, code_host.is_github() || issue_tracker == IssueTrackerKind::GitHub);
+        
         Ok(Config {
-            jira_url: std::env::var("JIRA_URL")
-                .with_app_type(AppErrorType::ConfigError("JIRA_URL이 설정되지 않았습니다".into()))?,
-            jira_project: std::env::var("JIRA_PROJECT")
-                .with_app_type(AppErrorType::ConfigError("JIRA_PROJECT가 설정되지 않았습니다".into()))?,
-            jira_username: std::env::var("JIRA_USERNAME")
-                .with_app_type(AppErrorType::ConfigError("JIRA_USERNAME이 설정되지 않았습니다".into()))?,
-            jira_token: std::env::var("JIRA_TOKEN")
-                .with_app_type(AppErrorType::ConfigError("JIRA_TOKEN이 설정되지 않았습니다".into()))?,
+            jira_url: jira_var("JIRA_URL")?,
+            jira_project: jira_var("JIRA_PROJECT")?,
+            // Server는 개인 액세스 토큰(Bearer)만으로 인증할 수 있어 사용자 이름이 없어도 됩니다
+            jira_username: required_var("JIRA_USERNAME", issue_tracker.is_jira() && jira_flavor.is_cloud())?,
+            jira_token: jira_var("JIRA_TOKEN")?,
+            jira_flavor,
+            jira_ticket_pattern: std::env::var("JIRA_TICKET_PATTERN").ok(),
+            jira_boards: std::env::var("JIRA_BOARDS")
+                .map(|boards| boards.split(',').filter_map(|id| id.trim().parse().ok()).collect())
+                .unwrap_or_default(),
+            
+            issue_tracker,
+            ticket_prefix: match issue_tracker {
+                IssueTrackerKind::Jira => jira_var("JIRA_PROJECT")?,
+                IssueTrackerKind::GitHub => std::env::var("GITHUB_ISSUE_PREFIX")
+                    .unwrap_or_else(|_| GITHUB_ISSUE_PREFIX.to_string()),
+            },
+            
+            github_token: github_var("GITHUB_TOKEN")?,
+            github_api_url,
+            github_web_url,
+            repo_owner: github_var("REPO_OWNER")?,
+            repo_name: github_var("REPO_NAME")?,
             
-            github_token: std::env::var("GITHUB_TOKEN")
-                .with_app_type(AppErrorType::ConfigError("GITHUB_TOKEN이 설정되지 않았습니다".into()))?,
-            repo_owner: std::env::var("REPO_OWNER")
-                .with_app_type(AppErrorType::ConfigError("REPO_OWNER가 설정되지 않았습니다".into()))?,
-            repo_name: std::env::var("REPO_NAME")
-                .with_app_type(AppErrorType::ConfigError("REPO_NAME이 설정되지 않았습니다".into()))?,
+            code_host,
+            gitlab_url: std::env::var("GITLAB_URL")
+                .map(|url| url.trim_end_matches('/').to_string())
+                .unwrap_or_else(|_| GITLAB_WEB_URL.to_string()),
+            gitlab_project: std::env::var("GITLAB_PROJECT").unwrap_or_default(),
+            gitlab_token: std::env::var("GITLAB_TOKEN").unwrap_or_default(),
+            bitbucket_workspace: std::env::var("BITBUCKET_WORKSPACE").unwrap_or_default(),
+            bitbucket_repo: std::env::var("BITBUCKET_REPO").unwrap_or_default(),
+            bitbucket_username: std::env::var("BITBUCKET_USERNAME").ok(),
+            bitbucket_token: std::env::var("BITBUCKET_TOKEN").unwrap_or_default(),
             
             default_branch: std::env::var("DEFAULT_BRANCH")
                 .unwrap_or_else(|_| "main".to_string()),
@@ -177,31 +474,94 @@ impl Config {
             
             project_root: None,
             pr_template_content: None,
+            
+            branch_template: std::env::var("BRANCH_TEMPLATE").ok(),
+            branch_types: HashMap::new(),
+            
+            workflow: WorkflowConfig::default(),
+            filters: HashMap::new(),
+            start: StartConfig::default(),
+            worklog: WorklogConfig::default(),
+            release_notes_template: None,
+            release_notes_path: None,
+            release_notes_item: None,
         })
     }
     
-    pub fn validate(&self) -> AppResult<()> {
-        if self.jira_url.is_empty() {
-            return Err(AppError::validation_error("JIRA_URL이 비어있습니다"));
+    fn validate_github(&self) -> AppResult<()> {
+        if self.github_token.is_empty() {
+            return Err(AppError::validation_error("GITHUB_TOKEN이 비어있습니다"));
         }
         
-        if !self.jira_url.starts_with("https://") && !self.jira_url.starts_with("http://") {
-            return Err(AppError::validation_error("JIRA_URL은 http:// 또는 https://로 시작해야 합니다"));
+        if self.repo_owner.is_empty() || self.repo_name.is_empty() {
+            return Err(AppError::validation_error("jgf.json의 github.owner와 github.repo를 설정해주세요"));
         }
         
-        let jira_url = self.jira_url.trim_end_matches('/');
-        if jira_url != self.jira_url {
-            println!("💡 {}", "JIRA_URL 끝의 슬래시를 자동으로 제거했습니다".yellow());
+        for url in [&self.github_api_url, &self.github_web_url] {
+            if !url.starts_with("https://") && !url.starts_with("http://") {
+                return Err(AppError::validation_error(format!("GitHub 주소는 http:// 또는 https://로 시작해야 합니다: {}", url)));
+            }
         }
         
-        if self.github_token.is_empty() {
-            return Err(AppError::validation_error("GITHUB_TOKEN이 비어있습니다"));
+        Ok(())
+    }
+    
+    pub fn validate(&self) -> AppResult<()> {
+        if self.issue_tracker.is_jira() {
+            if self.jira_url.is_empty() {
+                return Err(AppError::validation_error("JIRA_URL이 비어있습니다"));
+            }
+            
+            if !self.jira_url.starts_with("https://") && !self.jira_url.starts_with("http://") {
+                return Err(AppError::validation_error("JIRA_URL은 http:// 또는 https://로 시작해야 합니다"));
+            }
+            
+            let jira_url = self.jira_url.trim_end_matches('/');
+            if jira_url != self.jira_url {
+                println!("💡 {}", "JIRA_URL 끝의 슬래시를 자동으로 제거했습니다".yellow());
+            }
+        }
+        
+        // GitHub Issues 트래커는 코드 호스트와 관계없이 github 설정을 사용합니다
+        if self.code_host.is_github() || self.issue_tracker == IssueTrackerKind::GitHub {
+            self.validate_github()?;
         }
         
-        if !self.jira_project.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
-            return Err(AppError::validation_error("JIRA_PROJECT는 대문자와 숫자만 포함해야 합니다"));
+        match self.code_host {
+            CodeHostKind::GitHub => {}
+            CodeHostKind::GitLab => {
+                if self.gitlab_token.is_empty() {
+                    return Err(AppError::validation_error("GITLAB_TOKEN이 비어있습니다"));
+                }
+                
+                if self.gitlab_project.is_empty() {
+                    return Err(AppError::validation_error("jgf.json의 gitlab.project를 설정해주세요 (예: group/repo)"));
+                }
+                
+                if !self.gitlab_url.starts_with("https://") && !self.gitlab_url.starts_with("http://") {
+                    return Err(AppError::validation_error(format!("GitLab 주소는 http:// 또는 https://로 시작해야 합니다: {}", self.gitlab_url)));
+                }
+            }
+            CodeHostKind::Bitbucket => {
+                if self.bitbucket_token.is_empty() {
+                    return Err(AppError::validation_error("BITBUCKET_TOKEN이 비어있습니다"));
+                }
+                
+                if self.bitbucket_workspace.is_empty() || self.bitbucket_repo.is_empty() {
+                    return Err(AppError::validation_error("jgf.json의 bitbucket.workspace와 bitbucket.repo를 설정해주세요"));
+                }
+            }
         }
         
+        if !self.ticket_prefix.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit()) {
+            return Err(AppError::validation_error(match self.issue_tracker {
+                IssueTrackerKind::Jira => "JIRA_PROJECT는 대문자와 숫자만 포함해야 합니다",
+                IssueTrackerKind::GitHub => "github.issuePrefix는 대문자와 숫자만 포함해야 합니다",
+            }));
+        }
+        
+        self.ticket_key_parser()?;
+        
         Ok(())
     }
     
@@ -219,17 +579,34 @@ impl Config {
         
         let project_config = ProjectConfig {
             project: "my-project".to_string(),
-            jira: JiraConfig {
+            jira: Some(JiraConfig {
                 url: "https://your-company.atlassian.net".to_string(),
                 project: "PROJ".to_string(),
+                flavor: JiraFlavor::default(),
                 username: None,
-            },
-            github: GithubConfig {
+                ticket_pattern: None,
+                boards: Vec::new(),
+            }),
+            tracker: IssueTrackerKind::default(),
+            host: CodeHostKind::default(),
+            github: Some(GithubConfig {
                 owner: "your-org".to_string(),
                 repo: "your-repo".to_string(),
-            },
+                api_url: None,
+                web_url: None,
+                issue_prefix: None,
+            }),
+            gitlab: None,
+            bitbucket: None,
             default_branch: "main".to_string(),
             pr_template: None,
+            branch_template: Some("{type}/{key}-{slug}".to_string()),
+            branch_types: None,
+            workflow: None,
+            filters: None,
+            start: None,
+            worklog: None,
+            release_notes: None,
         };
         
         let config_content = serde_json::to_string_pretty(&project_config)
@@ -244,6 +621,14 @@ impl Config {
                                JIRA_TOKEN=your-jira-api-token\n\
                                GITHUB_TOKEN=your-github-token\n\
                                \n\
+                               # jgf.json의 tracker가 github이면 JIRA_TOKEN은 필요 없습니다\n\
+                               \n\
+                               # Optional: jgf.json의 host가 gitlab일 경우 사용\n\
+                               # GITLAB_TOKEN=your-gitlab-token\n\
+                               \n\
+                               # Optional: jgf.json의 host가 bitbucket일 경우 사용 (액세스 토큰 또는 앱 비밀번호)\n\
+                               # BITBUCKET_TOKEN=your-bitbucket-token\n\
+                               \n\
                                # Optional: JIRA_USERNAME이 jgf.json에 없을 경우 사용\n\
                                # JIRA_USERNAME=your-email@example.com\n";
             
@@ -268,15 +653,71 @@ impl Config {
     }
     
     pub fn get_github_repo_url(&self) -> String {
-        format!("https://github.com/{}/{}", self.repo_owner, self.repo_name)
+        format!("{}/{}/{}", self.github_web_url, self.repo_owner, self.repo_name)
     }
     
-    pub fn get_jira_ticket_url(&self, ticket_key: &str) -> String {
-        format!("{}/browse/{}", self.get_jira_base_url(), ticket_key)
+    pub fn get_github_api_repo_url(&self) -> String {
+        format!("{}/repos/{}/{}", self.github_api_url, self.repo_owner, self.repo_name)
     }
     
-    pub fn format_branch_name(&self, ticket_key: &str, _summary: Option<&str>) -> String {
-        ticket_key.to_uppercase()
+    pub fn ticket_key_parser(&self) -> AppResult<TicketKeyParser> {
+        TicketKeyParser::new(&self.ticket_prefix, self.jira_ticket_pattern.as_deref())
+    }
+    
+    pub fn get_ticket_url(&self, ticket_key: &str) -> String {
+        match self.issue_tracker {
+            IssueTrackerKind::Jira => format!("{}/browse/{}", self.get_jira_base_url(), ticket_key),
+            IssueTrackerKind::GitHub => {
+                let number = ticket_key.rsplit('-').next().unwrap_or(ticket_key);
+                format!("{}/issues/{}", self.get_github_repo_url(), number)
+            }
+        }
+    }
+    
+    pub fn format_branch_name(&self, ticket_key: &str, issue_type: Option<&str>, summary: Option<&str>) -> String {
+        let key = ticket_key.to_uppercase();
+        
+        let Some(template) = &self.branch_template else {
+            return key;
+        };
+        
+        let branch_type = issue_type
+            .map(|name| self.branch_type_for(name))
+            .unwrap_or_default();
+        let slug = summary
+            .map(|summary| utils::slugify(summary, BRANCH_SLUG_MAX_LENGTH))
+            .unwrap_or_default();
+        
+        let branch_name = template
+            .replace("{type}", &branch_type)
+            .replace("{key}", &key)
+            .replace("{slug}", &slug);
+        
+        utils::sanitize_branch_name(&branch_name)
+    }
+    
+    fn branch_type_for(&self, issue_type: &str) -> String {
+        if let Some(mapped) = self.branch_types
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(issue_type))
+            .map(|(_, mapped)| mapped) {
+            return mapped.clone();
+        }
+        
+        match issue_type.to_lowercase().as_str() {
+            "bug" | "버그" => "fix".to_string(),
+            "story" | "스토리" | "new feature" => "feature".to_string(),
+            other => utils::slugify(other, BRANCH_SLUG_MAX_LENGTH),
+        }
+    }
+    
+    // 타이머 등 로컬 상태를 저장하는 디렉토리 (<프로젝트 루트>/.jgf)
+    pub fn state_dir(&self) -> PathBuf {
+        self.project_root
+            .clone()
+            .or_else(|| env::current_dir().ok())
+            .unwrap_or_default()
+            .join(".jgf")
     }
     
     pub fn display_info(&self) {
@@ -285,9 +726,35 @@ impl Config {
         if let Some(ref root) = self.project_root {
             println!("  {}: {}", "프로젝트 경로".bold(), root.display());
         }
-        println!("  {}: {}", "Jira URL".bold(), self.jira_url);
-        println!("  {}: {}", "Jira 프로젝트".bold(), self.jira_project);
-        println!("  {}: {}/{}", "GitHub".bold(), self.repo_owner, self.repo_name);
+        match self.issue_tracker {
+            IssueTrackerKind::Jira => {
+                println!("  {}: {}", "Jira URL".bold(), self.jira_url);
+                println!("  {}: {}", "Jira 프로젝트".bold(), self.jira_project);
+                if !self.jira_flavor.is_cloud() {
+                    println!("  {}: Server / Data Center", "Jira 종류".bold());
+                }
+            }
+            IssueTrackerKind::GitHub => {
+                println!("  {}: GitHub Issues ({}-번호)", "이슈 트래커".bold(), self.ticket_prefix);
+            }
+        }
+        match self.code_host {
+            CodeHostKind::GitHub => {
+                println!("  {}: {}/{}", "GitHub".bold(), self.repo_owner, self.repo_name);
+                if self.github_web_url != GITHUB_WEB_URL {
+                    println!("  {}: {}", "GitHub 주소".bold(), self.github_web_url);
+                }
+            }
+            CodeHostKind::GitLab => {
+                println!("  {}: {}", "GitLab".bold(), self.gitlab_project);
+                if self.gitlab_url != GITLAB_WEB_URL {
+                    println!("  {}: {}", "GitLab 주소".bold(), self.gitlab_url);
+                }
+            }
+            CodeHostKind::Bitbucket => {
+                println!("  {}: {}/{}", "Bitbucket".bold(), self.bitbucket_workspace, self.bitbucket_repo);
+            }
+        }
         println!("  {}: {}", "기본 브랜치".bold(), self.default_branch);
         println!();
     }
@@ -302,17 +769,56 @@ impl Config {
                 root.join("docs").join("pull_request_template.md"),
                 root.join("docs").join("PULL_REQUEST_TEMPLATE.md"),
                 root.join(".gitlab").join("merge_request_templates").join("default.md"),
+                root.join(".bitbucket").join("pull_request_template.md"),
+                root.join(".bitbucket").join("PULL_REQUEST_TEMPLATE.md"),
             ];
             
             for path in possible_paths {
-                if path.exists() {
-                    if let Ok(content) = fs::read_to_string(&path) {
-                        return Some(content);
-                    }
+                if path.exists()
+                    && let Ok(content) = fs::read_to_string(&path) {
+                    return Some(content);
                 }
             }
         }
         
         None
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::AppErrorType;
+    
+    fn resolve(api_url: Option<&str>, web_url: Option<&str>) -> AppResult<(String, String)> {
+        resolve_github_urls(api_url.map(str::to_string), web_url.map(str::to_string))
+    }
+    
+    #[test]
+    fn defaults_to_github_com() {
+        let urls = (GITHUB_API_URL.to_string(), GITHUB_WEB_URL.to_string());
+        assert_eq!(resolve(None, None).unwrap(), urls);
+        assert_eq!(resolve(Some("https://api.github.com/"), None).unwrap(), urls);
+        assert_eq!(resolve(None, Some("https://github.com")).unwrap(), urls);
+    }
+    
+    #[test]
+    fn derives_enterprise_urls_from_either_side() {
+        let urls = ("https://ghe.example.com/api/v3".to_string(), "https://ghe.example.com".to_string());
+        assert_eq!(resolve(None, Some("https://ghe.example.com/")).unwrap(), urls);
+        assert_eq!(resolve(Some("https://ghe.example.com/api/v3/"), None).unwrap(), urls);
+    }
+    
+    #[test]
+    fn keeps_both_urls_when_given() {
+        assert_eq!(
+            resolve(Some("https://api.ghe.example.com"), Some("https://ghe.example.com")).unwrap(),
+            ("https://api.ghe.example.com".to_string(), "https://ghe.example.com".to_string())
+        );
+    }
+    
+    #[test]
+    fn requires_web_url_for_non_standard_api_url() {
+        let error = resolve(Some("https://api.ghe.example.com"), None).unwrap_err();
+        assert!(matches!(error.error_type, AppErrorType::ConfigError(ref message) if message.contains("webUrl")));
+    }
+}
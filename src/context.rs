@@ -1,14 +1,19 @@
+use crate::code_host::{CodeHost, CodeHostKind};
 use crate::config::Config;
 use crate::error::{AppError, AppResult};
+use crate::issue_tracker::{IssueTracker, IssueTrackerKind};
 use crate::jira::JiraClient;
 use crate::github::GitHubClient;
+use crate::gitlab::GitLabClient;
+use crate::bitbucket::BitbucketClient;
 use std::sync::Arc;
 
 #[derive(Clone)]
 pub struct AppContext {
     pub config: Arc<Config>,
     pub jira_client: Option<Arc<JiraClient>>,
-    pub github_client: Option<Arc<GitHubClient>>,
+    pub issue_tracker: Option<Arc<dyn IssueTracker>>,
+    pub code_host: Option<Arc<dyn CodeHost>>,
 }
 
 
@@ -17,17 +22,25 @@ impl AppContext {
         AppContext {
             config: Arc::new(config),
             jira_client: None,
-            github_client: None,
+            issue_tracker: None,
+            code_host: None,
         }
     }
     
     pub fn with_jira_client(mut self, client: JiraClient) -> Self {
-        self.jira_client = Some(Arc::new(client));
+        let client = Arc::new(client);
+        self.jira_client = Some(client.clone());
+        self.issue_tracker = Some(client);
         self
     }
     
-    pub fn with_github_client(mut self, client: GitHubClient) -> Self {
-        self.github_client = Some(Arc::new(client));
+    pub fn with_issue_tracker(mut self, tracker: impl IssueTracker + 'static) -> Self {
+        self.issue_tracker = Some(Arc::new(tracker));
+        self
+    }
+    
+    pub fn with_code_host(mut self, host: impl CodeHost + 'static) -> Self {
+        self.code_host = Some(Arc::new(host));
         self
     }
     
@@ -35,31 +48,76 @@ impl AppContext {
         &self.config
     }
     
+    // 스프린트, 버전, 작업 시간 기록 등 Jira에만 있는 기능에서 사용합니다
     pub fn jira_client(&self) -> AppResult<&JiraClient> {
+        if !self.config.issue_tracker.is_jira() {
+            return Err(AppError::config_error("이 기능은 Jira 이슈 트래커에서만 사용할 수 있습니다"));
+        }
+        
         self.jira_client
             .as_ref()
             .map(|c| c.as_ref())
             .ok_or_else(|| AppError::config_error("Jira 클라이언트가 초기화되지 않았습니다"))
     }
     
-    pub fn github_client(&self) -> AppResult<&GitHubClient> {
-        self.github_client
+    pub fn issue_tracker(&self) -> AppResult<&dyn IssueTracker> {
+        self.issue_tracker
+            .as_ref()
+            .map(|c| c.as_ref())
+            .ok_or_else(|| AppError::config_error("이슈 트래커 클라이언트가 초기화되지 않았습니다"))
+    }
+    
+    pub fn code_host(&self) -> AppResult<&dyn CodeHost> {
+        self.code_host
             .as_ref()
             .map(|c| c.as_ref())
-            .ok_or_else(|| AppError::config_error("GitHub 클라이언트가 초기화되지 않았습니다"))
+            .ok_or_else(|| AppError::config_error("코드 호스트 클라이언트가 초기화되지 않았습니다"))
+    }
+    
+    // jira.boards가 없으면 프로젝트의 scrum 보드를 조회해서 사용합니다
+    pub async fn board_ids(&self) -> AppResult<Vec<u64>> {
+        if !self.config.jira_boards.is_empty() {
+            return Ok(self.config.jira_boards.clone());
+        }
+        
+        let boards = self.jira_client()?.get_project_boards().await?;
+        let board_ids: Vec<u64> = boards
+            .iter()
+            .filter(|board| board.board_type.as_deref() == Some("scrum"))
+            .map(|board| board.id)
+            .collect();
+        
+        if board_ids.is_empty() {
+            return Err(AppError::config_error("스프린트를 사용하는 보드를 찾을 수 없습니다. jgf.json의 jira.boards에 보드 ID를 설정하세요"));
+        }
+        
+        Ok(board_ids)
     }
     
     pub async fn init_clients(mut self) -> AppResult<Self> {
-        let jira_client = JiraClient::new(
-            self.config.get_jira_base_url(),
-            self.config.jira_username.clone(),
-            self.config.jira_token.clone(),
-            self.config.jira_project.clone(),
-        )?;
-        self.jira_client = Some(Arc::new(jira_client));
+        match self.config.issue_tracker {
+            IssueTrackerKind::Jira => {
+                let jira_client = Arc::new(JiraClient::new(
+                    self.config.get_jira_base_url(),
+                    self.config.jira_username.clone(),
+                    self.config.jira_token.clone(),
+                    self.config.jira_project.clone(),
+                    self.config.jira_flavor,
+                )?);
+                self.jira_client = Some(jira_client.clone());
+                self.issue_tracker = Some(jira_client);
+            }
+            IssueTrackerKind::GitHub => {
+                self.issue_tracker = Some(Arc::new(GitHubClient::new(&self.config)?));
+            }
+        }
         
-        let github_client = GitHubClient::new(&self.config)?;
-        self.github_client = Some(Arc::new(github_client));
+        let code_host: Arc<dyn CodeHost> = match self.config.code_host {
+            CodeHostKind::GitHub => Arc::new(GitHubClient::new(&self.config)?),
+            CodeHostKind::GitLab => Arc::new(GitLabClient::new(&self.config)?),
+            CodeHostKind::Bitbucket => Arc::new(BitbucketClient::new(&self.config)?),
+        };
+        self.code_host = Some(code_host);
         
         Ok(self)
     }
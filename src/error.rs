@@ -26,6 +26,13 @@ pub enum AppErrorType {
     JiraAuthenticationError,
     JiraTicketNotFound(String),
     JiraTransitionNotAllowed,
+    JiraTransitionStepFailed {
+        step: usize,
+        total: usize,
+        transition: String,
+        reason: String,
+    },
+    JiraBadRequest(String),
     JiraApiError(String),
     
     GitHubConnectionError,
@@ -34,6 +41,13 @@ pub enum AppErrorType {
     GitHubPrCreateFailed,
     GitHubApiError(String),
     
+    GitLabConnectionError,
+    GitLabAuthenticationError,
+    GitLabApiError(String),
+    BitbucketConnectionError,
+    BitbucketAuthenticationError,
+    BitbucketApiError(String),
+    
     Unknown(String),
 }
 
@@ -73,6 +87,15 @@ impl fmt::Display for AppError {
             AppErrorType::JiraAuthenticationError => write!(f, "❌ Jira 인증 실패"),
             AppErrorType::JiraTicketNotFound(ticket) => write!(f, "❌ Jira 티켓을 찾을 수 없음: {}", ticket),
             AppErrorType::JiraTransitionNotAllowed => write!(f, "❌ Jira 상태 변경이 허용되지 않습니다"),
+            AppErrorType::JiraTransitionStepFailed { step, total, transition, reason } => write!(
+                f,
+                "❌ Jira 상태 전환 {}/{}단계 실패 ({}): {}",
+                step,
+                total,
+                transition,
+                reason.trim_start_matches("❌ ")
+            ),
+            AppErrorType::JiraBadRequest(msg) => write!(f, "❌ Jira 요청 오류: {}", msg),
             AppErrorType::JiraApiError(msg) => write!(f, "❌ Jira API 오류: {}", msg),
             
             AppErrorType::GitHubConnectionError => write!(f, "❌ GitHub 연결 실패"),
@@ -81,6 +104,13 @@ impl fmt::Display for AppError {
             AppErrorType::GitHubPrCreateFailed => write!(f, "❌ PR 생성 실패"),
             AppErrorType::GitHubApiError(msg) => write!(f, "❌ GitHub API 오류: {}", msg),
             
+            AppErrorType::GitLabConnectionError => write!(f, "❌ GitLab 연결 실패"),
+            AppErrorType::GitLabAuthenticationError => write!(f, "❌ GitLab 인증 실패"),
+            AppErrorType::GitLabApiError(msg) => write!(f, "❌ GitLab API 오류: {}", msg),
+            AppErrorType::BitbucketConnectionError => write!(f, "❌ Bitbucket 연결 실패"),
+            AppErrorType::BitbucketAuthenticationError => write!(f, "❌ Bitbucket 인증 실패"),
+            AppErrorType::BitbucketApiError(msg) => write!(f, "❌ Bitbucket API 오류: {}", msg),
+            
             AppErrorType::Unknown(msg) => write!(f, "❌ 오류: {}", msg),
         }
     }
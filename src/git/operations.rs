@@ -1,5 +1,6 @@
 use crate::{AppResult, AppErrorType};
 use crate::error::AppErrorExt;
+use crate::jira::TicketKeyParser;
 use git2::{Repository, BranchType};
 
 pub struct GitOps {
@@ -96,6 +97,7 @@ impl GitOps {
         Ok(())
     }
     
+    #[allow(clippy::needless_borrows_for_generic_args)]
     pub fn pull_latest(&self, branch_name: &str) -> AppResult<()> {
         let current_branch = self.get_current_branch()?;
         if current_branch != branch_name {
@@ -128,6 +130,15 @@ impl GitOps {
         }
     }
     
+    pub fn branch_commit(&self, branch_name: &str) -> AppResult<String> {
+        let branch = self.repo.find_branch(branch_name, BranchType::Local)
+            .with_app_type(AppErrorType::GitError(format!("브랜치 '{}'를 찾을 수 없습니다", branch_name)))?;
+        let commit = branch.get().peel_to_commit()
+            .with_app_type(AppErrorType::GitError(format!("브랜치 '{}'의 커밋을 찾을 수 없습니다", branch_name)))?;
+        
+        Ok(commit.id().to_string())
+    }
+    
     pub fn list_branches(&self) -> AppResult<Vec<String>> {
         let branches = self.repo.branches(Some(BranchType::Local))
             .with_app_type(AppErrorType::GitError("브랜치 목록 가져오기 실패".to_string()))?;
@@ -145,4 +156,92 @@ impl GitOps {
         
         Ok(branch_names)
     }
+    
+    pub fn get_current_ticket_key(&self, key_parser: &TicketKeyParser) -> AppResult<Option<String>> {
+        let current_branch = self.get_current_branch()?;
+        Ok(key_parser.extract(&current_branch))
+    }
+    
+    pub fn find_ticket_branch(&self, key_parser: &TicketKeyParser, ticket_key: &str) -> AppResult<Option<String>> {
+        let branches = self.list_branches()?;
+        
+        Ok(branches
+            .into_iter()
+            .find(|branch| key_parser.extract(branch).as_deref() == Some(ticket_key)))
+    }
+    
+    // 상위 티켓 브랜치에서 분기한 경우 PR 대상 브랜치를 git config(branch.<name>.jgfBase)에 기록합니다
+    pub fn set_branch_base(&self, branch_name: &str, base_branch: &str) -> AppResult<()> {
+        let mut config = self.repo.config()
+            .with_app_type(AppErrorType::GitError("Git 설정을 열 수 없습니다".to_string()))?;
+        
+        config.set_str(&format!("branch.{}.jgfBase", branch_name), base_branch)
+            .with_app_type(AppErrorType::GitError("브랜치 기준 정보 저장 실패".to_string()))?;
+        
+        Ok(())
+    }
+    
+    pub fn get_branch_base(&self, branch_name: &str) -> AppResult<Option<String>> {
+        let config = self.repo.config()
+            .with_app_type(AppErrorType::GitError("Git 설정을 열 수 없습니다".to_string()))?;
+        
+        match config.get_string(&format!("branch.{}.jgfBase", branch_name)) {
+            Ok(base) => Ok(Some(base)),
+            Err(e) if e.code() == git2::ErrorCode::NotFound => Ok(None),
+            Err(e) => Err(AppErrorType::GitError(format!("브랜치 기준 정보 조회 실패: {}", e)).into()),
+        }
+    }
+    
+    // "v1.0..v1.1" 또는 "v1.0"(HEAD까지) 형식의 범위를 (from, to)로 나눕니다
+    pub fn split_range(range: &str) -> (&str, &str) {
+        match range.split_once("..") {
+            Some((from, to)) => (from, if to.is_empty() { "HEAD" } else { to }),
+            None => (range, "HEAD"),
+        }
+    }
+    
+    pub fn commit_messages_in_range(&self, range: &str) -> AppResult<Vec<String>> {
+        let (from, to) = Self::split_range(range);
+        
+        let resolve = |spec: &str| {
+            self.repo.revparse_single(spec)
+                .and_then(|object| object.peel_to_commit())
+                .map(|commit| commit.id())
+                .with_app_type(AppErrorType::GitError(format!("'{}'를 찾을 수 없습니다", spec)))
+        };
+        
+        let mut revwalk = self.repo.revwalk()
+            .with_app_type(AppErrorType::GitError("커밋 탐색 실패".to_string()))?;
+        revwalk.push(resolve(to)?)
+            .with_app_type(AppErrorType::GitError("커밋 탐색 실패".to_string()))?;
+        if !from.is_empty() {
+            revwalk.hide(resolve(from)?)
+                .with_app_type(AppErrorType::GitError("커밋 탐색 실패".to_string()))?;
+        }
+        
+        let mut messages = Vec::new();
+        for oid in revwalk {
+            let oid = oid.with_app_type(AppErrorType::GitError("커밋 탐색 실패".to_string()))?;
+            let commit = self.repo.find_commit(oid)
+                .with_app_type(AppErrorType::GitError("커밋 정보 가져오기 실패".to_string()))?;
+            messages.push(commit.message().unwrap_or_default().to_string());
+        }
+        
+        Ok(messages)
+    }
+    
+    // 머지 커밋의 브랜치명과 커밋 메시지에서 티켓 키를 모읍니다 (처음 등장한 순서)
+    pub fn ticket_keys_in_range(&self, key_parser: &TicketKeyParser, range: &str) -> AppResult<Vec<String>> {
+        let mut keys: Vec<String> = Vec::new();
+        
+        for message in self.commit_messages_in_range(range)? {
+            for key in key_parser.extract_all(&message) {
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            }
+        }
+        
+        Ok(keys)
+    }
 }
\ No newline at end of file
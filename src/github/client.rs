@@ -1,13 +1,24 @@
 use crate::{AppResult, AppErrorType, Config};
+use crate::code_host::{ChangeRequest, CodeHost};
 use crate::error::AppErrorExt;
 use crate::github::models::*;
+use crate::jira::WorkflowConfig;
+use async_trait::async_trait;
 use reqwest::{Client, header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT}};
+use serde_json::json;
 
 pub struct GitHubClient {
-    client: Client,
+    pub(super) client: Client,
+    #[allow(dead_code)]
     token: String,
     repo_owner: String,
-    repo_name: String,
+    pub(super) api_url: String,
+    pub(super) repo_api_url: String,
+    repo_web_url: String,
+    web_url: String,
+    // 이슈 트래커로 사용할 때의 티켓 키 접두사 (GH-12)와 상태 라벨 매핑
+    pub(super) issue_prefix: String,
+    pub(super) workflow: WorkflowConfig,
 }
 
 impl GitHubClient {
@@ -27,10 +38,30 @@ impl GitHubClient {
             client,
             token: config.github_token.clone(),
             repo_owner: config.repo_owner.clone(),
-            repo_name: config.repo_name.clone(),
+            api_url: config.github_api_url.clone(),
+            repo_api_url: config.get_github_api_repo_url(),
+            repo_web_url: config.get_github_repo_url(),
+            web_url: config.github_web_url.clone(),
+            issue_prefix: config.ticket_prefix.clone(),
+            workflow: config.workflow.clone(),
         })
     }
 
+    #[cfg(test)]
+    pub(super) fn for_tests(issue_prefix: &str, workflow: WorkflowConfig) -> Self {
+        GitHubClient {
+            client: Client::new(),
+            token: String::new(),
+            repo_owner: "owner".to_string(),
+            api_url: "https://api.github.com".to_string(),
+            repo_api_url: "https://api.github.com/repos/owner/repo".to_string(),
+            repo_web_url: "https://github.com/owner/repo".to_string(),
+            web_url: "https://github.com".to_string(),
+            issue_prefix: issue_prefix.to_string(),
+            workflow,
+        }
+    }
+
     pub async fn create_pull_request(
         &self,
         title: &str,
@@ -38,10 +69,7 @@ impl GitHubClient {
         head_branch: &str,
         base_branch: &str,
     ) -> AppResult<PullRequest> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/pulls",
-            self.repo_owner, self.repo_name
-        );
+        let url = format!("{}/pulls", self.repo_api_url);
 
         let request = CreatePullRequestRequest {
             title: title.to_string(),
@@ -72,10 +100,7 @@ impl GitHubClient {
     }
 
     pub async fn get_pull_request(&self, pr_number: u32) -> AppResult<PullRequest> {
-        let url = format!(
-            "https://api.github.com/repos/{}/{}/pulls/{}",
-            self.repo_owner, self.repo_name, pr_number
-        );
+        let url = format!("{}/pulls/{}", self.repo_api_url, pr_number);
 
         let response = self.client
             .get(&url)
@@ -96,4 +121,107 @@ impl GitHubClient {
 
         Ok(pull_request)
     }
-}
\ No newline at end of file
+
+    pub async fn find_pull_request_by_branch(&self, head_branch: &str) -> AppResult<Option<PullRequest>> {
+        let url = format!("{}/pulls", self.repo_api_url);
+
+        let head = format!("{}:{}", self.repo_owner, head_branch);
+        let response = self.client
+            .get(&url)
+            .query(&[("head", head.as_str()), ("state", "all")])
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppErrorType::GitHubApiError(
+                format!("PR 목록 조회 실패 ({}): {}", status, error_text)
+            ).into());
+        }
+
+        let pull_requests: Vec<PullRequest> = response.json().await
+            .with_app_type(AppErrorType::GitHubApiError("PR 응답 파싱 실패".to_string()))?;
+
+        Ok(pull_requests.into_iter().next())
+    }
+
+    pub async fn merge_pull_request(&self, pr_number: u32) -> AppResult<MergeResult> {
+        let url = format!("{}/pulls/{}/merge", self.repo_api_url, pr_number);
+
+        let response = self.client
+            .put(&url)
+            .json(&json!({}))
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppErrorType::GitHubApiError(
+                format!("PR 머지 실패 ({}): {}", status, error_text)
+            ).into());
+        }
+
+        let result: MergeResult = response.json().await
+            .with_app_type(AppErrorType::GitHubApiError("머지 응답 파싱 실패".to_string()))?;
+
+        Ok(result)
+    }
+}
+
+#[async_trait]
+impl CodeHost for GitHubClient {
+    fn name(&self) -> &'static str {
+        "GitHub"
+    }
+
+    fn change_request_label(&self) -> &'static str {
+        "PR"
+    }
+
+    fn change_request_name(&self) -> &'static str {
+        "Pull Request"
+    }
+
+    fn icon_url(&self) -> String {
+        format!("{}/favicon.ico", self.web_url)
+    }
+
+    fn branch_search_url(&self, branch: &str) -> String {
+        format!("{}/pulls?q=is%3Apr+head%3A{}", self.repo_web_url, branch)
+    }
+
+    async fn create_change_request(
+        &self,
+        title: &str,
+        body: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> AppResult<ChangeRequest> {
+        let pr = self.create_pull_request(title, body, source_branch, target_branch).await?;
+        Ok(pr.into())
+    }
+
+    async fn find_change_request(&self, source_branch: &str) -> AppResult<Option<ChangeRequest>> {
+        let pr = self.find_pull_request_by_branch(source_branch).await?;
+        Ok(pr.map(ChangeRequest::from))
+    }
+
+    async fn get_change_request(&self, number: u64) -> AppResult<ChangeRequest> {
+        let pr = self.get_pull_request(number as u32).await?;
+        Ok(pr.into())
+    }
+
+    async fn merge_change_request(&self, number: u64) -> AppResult<()> {
+        let result = self.merge_pull_request(number as u32).await?;
+        if !result.merged {
+            return Err(AppErrorType::GitHubApiError(
+                format!("PR 머지 실패: {}", result.message.unwrap_or_default())
+            ).into());
+        }
+        Ok(())
+    }
+}
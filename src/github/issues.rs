@@ -0,0 +1,566 @@
+use crate::{AppError, AppErrorType, AppResult};
+use crate::error::AppErrorExt;
+use crate::github::GitHubClient;
+use crate::github::models::{GitHubIssue, IssueComment, Label, User as GitHubUser};
+use crate::issue_tracker::IssueTracker;
+use crate::jira::{
+    Comment, Issue, IssueFields, IssueType, IssueUpdate, Project, SearchResults, Status, StatusCategory,
+    Transition, TransitionInput, TransitionPath, TransitionTarget, User, WorkflowConfig, WorkflowStage,
+};
+use async_trait::async_trait;
+use reqwest::{Response, StatusCode};
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use std::collections::HashMap;
+
+const PAGE_SIZE: usize = 100;
+
+// GitHub 이슈에는 워크플로우가 없으므로 열림/닫힘과 workflow 라벨로 상태를 표현합니다
+// 닫힘 → 완료, 리뷰 라벨 → 리뷰, 시작 라벨 → 진행 중, 그 외 → Open
+const STATUS_OPEN: &str = "open";
+const STATUS_START: &str = "start";
+const STATUS_REVIEW: &str = "review";
+const STATUS_CLOSED: &str = "closed";
+
+fn status(id: &str, name: &str, category: &str) -> Status {
+    let (category_id, category_name) = match category {
+        "done" => (3, "Done"),
+        "indeterminate" => (4, "In Progress"),
+        _ => (2, "To Do"),
+    };
+
+    Status {
+        id: id.to_string(),
+        name: name.to_string(),
+        description: None,
+        status_category: Some(StatusCategory {
+            id: category_id,
+            key: category.to_string(),
+            name: category_name.to_string(),
+        }),
+    }
+}
+
+fn to_user(user: &GitHubUser) -> User {
+    User {
+        account_id: None,
+        name: Some(user.login.clone()),
+        email_address: None,
+        display_name: Some(user.name.clone().unwrap_or_else(|| user.login.clone())),
+        active: Some(true),
+    }
+}
+
+fn to_comment(comment: IssueComment) -> Comment {
+    Comment {
+        id: comment.id.to_string(),
+        author: comment.user.as_ref().map(to_user),
+        body: comment.body.map(Value::String),
+        created: Some(comment.created_at),
+        updated: Some(comment.updated_at),
+    }
+}
+
+impl GitHubClient {
+    async fn handle_response<T: DeserializeOwned>(&self, response: Response, context: &str) -> AppResult<T> {
+        let status = response.status();
+
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(AppErrorType::GitHubAuthenticationError.into());
+        }
+
+        if status == StatusCode::NOT_FOUND {
+            return Err(AppError::not_found(format!("{}: 리소스를 찾을 수 없습니다", context)));
+        }
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppErrorType::GitHubApiError(
+                format!("{} ({}): {}", context, status, error_text)
+            ).into());
+        }
+
+        response.json().await
+            .with_app_type(AppErrorType::GitHubApiError(format!("{}: 응답 파싱 실패", context)))
+    }
+
+    // GH-12, #12, 12 형식을 모두 받습니다 (접두사는 설정한 issuePrefix만 허용)
+    fn issue_number(&self, issue_key: &str) -> AppResult<u64> {
+        let key = issue_key.trim();
+        let number = match key.split_once('-') {
+            Some((prefix, number)) if prefix.eq_ignore_ascii_case(&self.issue_prefix) => number,
+            Some(_) => "",
+            None => key.strip_prefix('#').unwrap_or(key),
+        };
+
+        Some(number)
+            .filter(|number| !number.is_empty() && number.chars().all(|c| c.is_ascii_digit()))
+            .and_then(|number| number.parse().ok())
+            .filter(|number| *number > 0)
+            .ok_or_else(|| AppError::validation_error(format!(
+                "GitHub 이슈 번호를 찾을 수 없습니다: {} (예: {}-12, #12, 12)",
+                issue_key, self.issue_prefix
+            )))
+    }
+
+    // workflow 매핑이 없으면 단계 이름(In Progress, In Review)을 라벨로 사용합니다
+    fn stage_labels(&self, stage: WorkflowStage) -> Vec<String> {
+        let names = self.workflow.names(stage);
+        if names.is_empty() { vec![stage.to_string()] } else { names.to_vec() }
+    }
+
+    fn open_status(&self) -> Status {
+        status(STATUS_OPEN, "Open", "new")
+    }
+
+    fn stage_status(&self, stage: WorkflowStage) -> Status {
+        let label = self.workflow.label(stage);
+        match stage {
+            WorkflowStage::Start => status(STATUS_START, &label, "indeterminate"),
+            WorkflowStage::Review => status(STATUS_REVIEW, &label, "indeterminate"),
+            WorkflowStage::Done => status(STATUS_CLOSED, &label, "done"),
+        }
+    }
+
+    fn status_of(&self, issue: &GitHubIssue) -> Status {
+        if issue.is_closed() {
+            return self.stage_status(WorkflowStage::Done);
+        }
+
+        for (stage, id) in [(WorkflowStage::Review, STATUS_REVIEW), (WorkflowStage::Start, STATUS_START)] {
+            if let Some(label) = self.stage_labels(stage).iter().find(|label| issue.has_label(label)) {
+                return status(id, label, "indeterminate");
+            }
+        }
+
+        self.open_status()
+    }
+
+    fn to_issue(&self, issue: GitHubIssue) -> Issue {
+        let status = self.status_of(&issue);
+
+        Issue {
+            id: issue.id.to_string(),
+            key: format!("{}-{}", self.issue_prefix, issue.number),
+            fields: IssueFields {
+                summary: issue.title,
+                description: issue.body.map(Value::String),
+                status,
+                priority: None,
+                assignee: issue.assignee.as_ref().map(to_user),
+                reporter: issue.user.as_ref().map(to_user),
+                created: issue.created_at,
+                updated: issue.updated_at,
+                issuetype: IssueType {
+                    id: "issue".to_string(),
+                    name: "Issue".to_string(),
+                    description: None,
+                    subtask: false,
+                },
+                project: Project {
+                    id: self.issue_prefix.clone(),
+                    key: self.issue_prefix.clone(),
+                    name: self.issue_prefix.clone(),
+                },
+                labels: issue.labels.into_iter().map(|label| label.name).collect(),
+                components: Vec::new(),
+                parent: None,
+                subtasks: Vec::new(),
+                issuelinks: Vec::new(),
+                fix_versions: Vec::new(),
+                attachments: Vec::new(),
+                extra: HashMap::new(),
+            },
+        }
+    }
+
+    // 현재 상태를 제외한 나머지 상태로 한 번에 전환할 수 있습니다
+    fn transitions_from(&self, from: &Status) -> Vec<Transition> {
+        let targets = [
+            (self.workflow.label(WorkflowStage::Start), self.stage_status(WorkflowStage::Start)),
+            (self.workflow.label(WorkflowStage::Review), self.stage_status(WorkflowStage::Review)),
+            ("Close".to_string(), self.stage_status(WorkflowStage::Done)),
+            ("Reopen".to_string(), self.open_status()),
+        ];
+
+        targets
+            .into_iter()
+            .filter(|(_, to)| to.id != from.id)
+            .map(|(name, to)| Transition {
+                id: to.id.clone(),
+                name,
+                to,
+                fields: HashMap::new(),
+            })
+            .collect()
+    }
+
+    pub async fn get_user(&self) -> AppResult<GitHubUser> {
+        let url = format!("{}/user", self.api_url);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+        self.handle_response(response, "사용자 정보 조회 실패").await
+    }
+
+    pub async fn get_github_issue(&self, number: u64) -> AppResult<GitHubIssue> {
+        let url = format!("{}/issues/{}", self.repo_api_url, number);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+        let issue: GitHubIssue = self.handle_response(response, &format!("이슈 #{} 조회 실패", number)).await?;
+        if issue.pull_request.is_some() {
+            return Err(AppError::not_found(format!("#{}은 이슈가 아닌 PR입니다", number)));
+        }
+
+        Ok(issue)
+    }
+
+    // PR을 제외한 이슈를 limit개까지 조회하고, status가 있으면 해당 상태의 이슈만 남깁니다
+    async fn list_issues(&self, query: &[(&str, &str)], status: Option<&str>, limit: Option<usize>) -> AppResult<SearchResults> {
+        let url = format!("{}/issues", self.repo_api_url);
+        let mut issues = Vec::new();
+
+        for page in 1.. {
+            let response = self.client
+                .get(&url)
+                .query(query)
+                .query(&[("per_page", PAGE_SIZE), ("page", page)])
+                .send()
+                .await
+                .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+            let items: Vec<GitHubIssue> = self.handle_response(response, "이슈 목록 조회 실패").await?;
+            let is_last_page = items.len() < PAGE_SIZE;
+
+            for item in items.into_iter().filter(|item| item.pull_request.is_none()) {
+                let issue = self.to_issue(item);
+                if status.is_some_and(|status| !issue.fields.status.name.eq_ignore_ascii_case(status)) {
+                    continue;
+                }
+                if limit.is_some_and(|limit| issues.len() >= limit) {
+                    return Ok(SearchResults { issues, is_last: false });
+                }
+                issues.push(issue);
+            }
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(SearchResults { issues, is_last: true })
+    }
+
+    async fn patch_issue(&self, number: u64, body: &Value) -> AppResult<GitHubIssue> {
+        let url = format!("{}/issues/{}", self.repo_api_url, number);
+
+        let response = self.client
+            .patch(&url)
+            .json(body)
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+        self.handle_response(response, &format!("이슈 #{} 수정 실패", number)).await
+    }
+
+    // 다른 단계 라벨을 지우고 대상 단계 라벨을 붙이며, 완료면 이슈를 닫습니다
+    async fn move_issue(&self, number: u64, status_id: &str) -> AppResult<()> {
+        let issue = self.get_github_issue(number).await?;
+        let stage_labels: Vec<String> = [WorkflowStage::Start, WorkflowStage::Review]
+            .into_iter()
+            .flat_map(|stage| self.stage_labels(stage))
+            .collect();
+
+        let mut labels: Vec<String> = issue.labels
+            .into_iter()
+            .map(|label| label.name)
+            .filter(|name| !stage_labels.iter().any(|label| label.eq_ignore_ascii_case(name)))
+            .collect();
+
+        let state = match status_id {
+            STATUS_START => {
+                labels.push(self.workflow.label(WorkflowStage::Start));
+                "open"
+            }
+            STATUS_REVIEW => {
+                labels.push(self.workflow.label(WorkflowStage::Review));
+                "open"
+            }
+            STATUS_CLOSED => "closed",
+            _ => "open",
+        };
+
+        self.patch_issue(number, &json!({ "state": state, "labels": labels })).await?;
+        Ok(())
+    }
+
+    pub async fn get_issue_comments(&self, number: u64) -> AppResult<Vec<IssueComment>> {
+        let url = format!("{}/issues/{}/comments", self.repo_api_url, number);
+        let mut comments = Vec::new();
+
+        for page in 1.. {
+            let response = self.client
+                .get(&url)
+                .query(&[("per_page", PAGE_SIZE), ("page", page)])
+                .send()
+                .await
+                .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+            let items: Vec<IssueComment> = self.handle_response(response, &format!("이슈 #{} 코멘트 조회 실패", number)).await?;
+            let is_last_page = items.len() < PAGE_SIZE;
+            comments.extend(items);
+
+            if is_last_page {
+                break;
+            }
+        }
+
+        Ok(comments)
+    }
+}
+
+#[async_trait]
+impl IssueTracker for GitHubClient {
+    fn name(&self) -> &'static str {
+        "GitHub Issues"
+    }
+
+    async fn get_issue(&self, issue_key: &str) -> AppResult<Issue> {
+        let issue = self.get_github_issue(self.issue_number(issue_key)?).await?;
+        Ok(self.to_issue(issue))
+    }
+
+    async fn search_assigned_issues(&self, limit: Option<usize>) -> AppResult<SearchResults> {
+        let login = self.get_user().await?.login;
+        let query = [("assignee", login.as_str()), ("state", "open"), ("sort", "updated")];
+
+        self.list_issues(&query, None, limit).await
+    }
+
+    async fn search_issues_by_status(&self, status: &str, limit: Option<usize>) -> AppResult<SearchResults> {
+        let done = self.stage_status(WorkflowStage::Done);
+
+        if status.eq_ignore_ascii_case(&done.name) || status.eq_ignore_ascii_case(STATUS_CLOSED) {
+            self.list_issues(&[("state", "closed"), ("sort", "updated")], None, limit).await
+        } else {
+            self.list_issues(&[("state", "open"), ("sort", "updated")], Some(status), limit).await
+        }
+    }
+
+    async fn get_transitions(&self, issue_key: &str) -> AppResult<Vec<Transition>> {
+        let issue = self.get_github_issue(self.issue_number(issue_key)?).await?;
+        Ok(self.transitions_from(&self.status_of(&issue)))
+    }
+
+    async fn find_transition_path(
+        &self,
+        issue_key: &str,
+        workflow: &WorkflowConfig,
+        target: &TransitionTarget,
+    ) -> AppResult<TransitionPath> {
+        let issue = self.get_github_issue(self.issue_number(issue_key)?).await?;
+        let from = self.status_of(&issue);
+        let transitions = self.transitions_from(&from);
+
+        match transitions.into_iter().find(|transition| target.matches(workflow, transition)) {
+            Some(transition) => Ok(TransitionPath { from, steps: vec![transition] }),
+            None => Err(AppError::github_api_error(format!(
+                "이슈 {}에서 '{}' 상태로 전환할 수 없습니다",
+                issue_key,
+                target.label(workflow)
+            ))),
+        }
+    }
+
+    // GitHub 이슈의 상태 전환에는 입력 필드가 없습니다
+    async fn apply_transition_path(
+        &self,
+        issue_key: &str,
+        path: &TransitionPath,
+        _inputs: &[TransitionInput],
+    ) -> AppResult<()> {
+        let number = self.issue_number(issue_key)?;
+        for step in &path.steps {
+            self.move_issue(number, &step.to.id).await?;
+        }
+        Ok(())
+    }
+
+    async fn get_comments(&self, issue_key: &str) -> AppResult<Vec<Comment>> {
+        let comments = self.get_issue_comments(self.issue_number(issue_key)?).await?;
+        Ok(comments.into_iter().map(to_comment).collect())
+    }
+
+    async fn upsert_comment(&self, issue_key: &str, link_url: &str, body: &str) -> AppResult<()> {
+        let number = self.issue_number(issue_key)?;
+        let myself = self.get_myself().await?;
+        let comments = self.get_issue_comments(number).await?;
+        let existing = comments
+            .into_iter()
+            .map(to_comment)
+            .find(|comment| comment.is_authored_by(&myself) && comment.has_link(link_url));
+
+        let request = match existing {
+            Some(comment) => self.client.patch(format!("{}/issues/comments/{}", self.repo_api_url, comment.id)),
+            None => self.client.post(format!("{}/issues/{}/comments", self.repo_api_url, number)),
+        };
+
+        let response = request
+            .json(&json!({ "body": body }))
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+        let _: IssueComment = self.handle_response(response, &format!("이슈 #{} 코멘트 작성 실패", number)).await?;
+        Ok(())
+    }
+
+    async fn get_myself(&self) -> AppResult<User> {
+        let user = self.get_user().await?;
+        Ok(to_user(&user))
+    }
+
+    // 담당자 지정과 라벨 추가만 지원합니다
+    async fn update_issue(&self, issue_key: &str, update: &IssueUpdate) -> AppResult<()> {
+        let number = self.issue_number(issue_key)?;
+
+        let unsupported: Vec<&str> = update.fields
+            .keys()
+            .chain(update.update.keys())
+            .map(String::as_str)
+            .filter(|field| !["assignee", "labels"].contains(field))
+            .collect();
+        if !unsupported.is_empty() {
+            return Err(AppError::validation_error(format!("GitHub Issues에서 지원하지 않는 항목입니다: {}", unsupported.join(", "))));
+        }
+
+        if let Some(login) = update.fields.get("assignee").and_then(|assignee| assignee.get("name")).and_then(Value::as_str) {
+            self.patch_issue(number, &json!({ "assignees": [login] })).await?;
+        }
+
+        let labels: Vec<&str> = update.update
+            .get("labels")
+            .and_then(Value::as_array)
+            .map(|operations| operations.iter().filter_map(|operation| operation.get("add").and_then(Value::as_str)).collect())
+            .unwrap_or_default();
+
+        if !labels.is_empty() {
+            let url = format!("{}/issues/{}/labels", self.repo_api_url, number);
+
+            let response = self.client
+                .post(&url)
+                .json(&json!({ "labels": labels }))
+                .send()
+                .await
+                .with_app_type(AppErrorType::GitHubConnectionError)?;
+
+            let _: Vec<Label> = self.handle_response(response, &format!("이슈 #{} 라벨 추가 실패", number)).await?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn client(workflow: WorkflowConfig) -> GitHubClient {
+        GitHubClient::for_tests("GH", workflow)
+    }
+
+    fn issue(state: &str, labels: &[&str]) -> GitHubIssue {
+        serde_json::from_value(json!({
+            "id": 1,
+            "number": 12,
+            "title": "로그인 오류",
+            "body": null,
+            "state": state,
+            "html_url": "https://github.com/o/r/issues/12",
+            "labels": labels.iter().map(|name| json!({ "name": name })).collect::<Vec<_>>(),
+            "assignee": null,
+            "user": null,
+            "created_at": "2024-03-04T01:15:30Z",
+            "updated_at": "2024-03-04T01:15:30Z",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn accepts_supported_key_formats() {
+        let client = client(WorkflowConfig::default());
+
+        for key in ["GH-12", "gh-12", "#12", "12", " GH-12 "] {
+            assert_eq!(client.issue_number(key).unwrap(), 12, "{}", key);
+        }
+    }
+
+    #[test]
+    fn rejects_malformed_keys() {
+        let client = client(WorkflowConfig::default());
+
+        for key in ["", "#", "GH-", "GH-12a", "OTHER-12", "GH-1-2", "#GH-12", "+12", "0", "abc"] {
+            assert!(client.issue_number(key).is_err(), "{}", key);
+        }
+    }
+
+    #[test]
+    fn maps_default_stage_labels_to_status() {
+        let client = client(WorkflowConfig::default());
+
+        assert_eq!(client.status_of(&issue("open", &[])).id, STATUS_OPEN);
+        assert_eq!(client.status_of(&issue("open", &["bug", "in progress"])).id, STATUS_START);
+        // 리뷰 라벨이 시작 라벨보다 우선합니다
+        assert_eq!(client.status_of(&issue("open", &["In Progress", "In Review"])).id, STATUS_REVIEW);
+        assert_eq!(client.status_of(&issue("closed", &["In Review"])).id, STATUS_CLOSED);
+    }
+
+    #[test]
+    fn maps_configured_workflow_labels() {
+        let workflow = WorkflowConfig {
+            start: vec!["doing".to_string()],
+            review: vec!["needs-review".to_string()],
+            ..WorkflowConfig::default()
+        };
+        let client = client(workflow);
+
+        let status = client.status_of(&issue("open", &["Doing"]));
+        assert_eq!(status.id, STATUS_START);
+        assert_eq!(status.name, "doing");
+        assert_eq!(client.status_of(&issue("open", &["needs-review"])).id, STATUS_REVIEW);
+        assert_eq!(client.status_of(&issue("open", &["In Progress"])).id, STATUS_OPEN);
+    }
+
+    #[test]
+    fn offers_reopen_only_for_closed_issues() {
+        let client = client(WorkflowConfig::default());
+        let names = |status: &Status| -> Vec<String> {
+            client.transitions_from(status).into_iter().map(|transition| transition.name).collect()
+        };
+
+        let closed = names(&client.status_of(&issue("closed", &[])));
+        assert!(closed.contains(&"Reopen".to_string()));
+        assert!(!closed.contains(&"Close".to_string()));
+
+        let open = names(&client.status_of(&issue("open", &[])));
+        assert!(open.contains(&"Close".to_string()));
+        assert!(!open.contains(&"Reopen".to_string()));
+
+        let reopen = client
+            .transitions_from(&client.stage_status(WorkflowStage::Done))
+            .into_iter()
+            .find(|transition| transition.name == "Reopen")
+            .unwrap();
+        assert_eq!(reopen.to.id, STATUS_OPEN);
+    }
+}
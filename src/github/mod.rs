@@ -1,4 +1,5 @@
 pub mod client;
+pub mod issues;
 pub mod models;
 
 pub use client::GitHubClient;
@@ -1,3 +1,5 @@
+use crate::code_host::{ChangeRequest, ChangeRequestState};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
@@ -15,10 +17,55 @@ pub struct PullRequest {
     pub title: String,
     pub body: Option<String>,
     pub html_url: String,
+    #[serde(default)]
+    pub state: String,
+    pub merged_at: Option<String>,
     pub head: BranchInfo,
     pub base: BranchInfo,
 }
 
+impl PullRequest {
+    pub fn is_merged(&self) -> bool {
+        self.merged_at.is_some()
+    }
+
+    pub fn format_state(&self) -> &str {
+        match self.state.as_str() {
+            _ if self.is_merged() => "머지됨",
+            "open" => "열림",
+            "closed" => "닫힘",
+            other => other,
+        }
+    }
+}
+
+impl From<PullRequest> for ChangeRequest {
+    fn from(pr: PullRequest) -> Self {
+        let state = match pr.state.as_str() {
+            _ if pr.is_merged() => ChangeRequestState::Merged,
+            "closed" => ChangeRequestState::Closed,
+            _ => ChangeRequestState::Open,
+        };
+
+        ChangeRequest {
+            number: pr.number as u64,
+            reference: format!("#{}", pr.number),
+            title: pr.title,
+            url: pr.html_url,
+            source_branch: pr.head.ref_name,
+            target_branch: pr.base.ref_name,
+            state,
+            head_sha: Some(pr.head.sha),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeResult {
+    pub merged: bool,
+    pub message: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct BranchInfo {
     #[serde(rename = "ref")]
@@ -37,4 +84,48 @@ pub struct Repository {
 pub struct User {
     pub login: String,
     pub id: u64,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Label {
+    pub name: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct GitHubIssue {
+    pub id: u64,
+    pub number: u64,
+    pub title: String,
+    pub body: Option<String>,
+    // open, closed
+    pub state: String,
+    pub html_url: String,
+    #[serde(default)]
+    pub labels: Vec<Label>,
+    pub assignee: Option<User>,
+    pub user: Option<User>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    // 이슈 목록 API는 PR도 함께 반환하며, PR에만 이 필드가 있습니다
+    pub pull_request: Option<serde_json::Value>,
+}
+
+impl GitHubIssue {
+    pub fn is_closed(&self) -> bool {
+        self.state == "closed"
+    }
+
+    pub fn has_label(&self, name: &str) -> bool {
+        self.labels.iter().any(|label| label.name.eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct IssueComment {
+    pub id: u64,
+    pub body: Option<String>,
+    pub user: Option<User>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
 }
\ No newline at end of file
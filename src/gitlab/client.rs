@@ -0,0 +1,178 @@
+use crate::{AppResult, AppErrorType, Config};
+use crate::code_host::{ChangeRequest, CodeHost};
+use crate::error::AppErrorExt;
+use crate::gitlab::models::*;
+use async_trait::async_trait;
+use reqwest::{Client, Response, StatusCode, header::{HeaderMap, HeaderValue, USER_AGENT}};
+
+pub struct GitLabClient {
+    client: Client,
+    web_url: String,
+    project_url: String,
+    project_api_url: String,
+}
+
+impl GitLabClient {
+    pub fn new(config: &Config) -> AppResult<Self> {
+        let mut headers = HeaderMap::new();
+        headers.insert("PRIVATE-TOKEN", HeaderValue::from_str(&config.gitlab_token)
+            .with_app_type(AppErrorType::GitLabAuthenticationError)?);
+        headers.insert(USER_AGENT, HeaderValue::from_static("jgf-cli"));
+
+        let client = Client::builder()
+            .default_headers(headers)
+            .build()
+            .with_app_type(AppErrorType::GitLabConnectionError)?;
+
+        // API는 "group/repo" 경로를 URL 인코딩한 값을 프로젝트 ID로 받습니다
+        let project_id = config.gitlab_project.replace('/', "%2F");
+
+        Ok(GitLabClient {
+            client,
+            web_url: config.gitlab_url.clone(),
+            project_url: format!("{}/{}", config.gitlab_url, config.gitlab_project),
+            project_api_url: format!("{}/api/v4/projects/{}", config.gitlab_url, project_id),
+        })
+    }
+
+    async fn handle_response<T: for<'de> serde::Deserialize<'de>>(
+        &self,
+        response: Response,
+        context: &str,
+    ) -> AppResult<T> {
+        let status = response.status();
+
+        if status == StatusCode::UNAUTHORIZED {
+            return Err(AppErrorType::GitLabAuthenticationError.into());
+        }
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(AppErrorType::GitLabApiError(
+                format!("{} ({}): {}", context, status, error_text)
+            ).into());
+        }
+
+        response.json().await
+            .with_app_type(AppErrorType::GitLabApiError(format!("{}: 응답 파싱 실패", context)))
+    }
+
+    pub async fn create_merge_request(
+        &self,
+        title: &str,
+        description: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> AppResult<MergeRequest> {
+        let url = format!("{}/merge_requests", self.project_api_url);
+
+        let request = CreateMergeRequestRequest {
+            source_branch: source_branch.to_string(),
+            target_branch: target_branch.to_string(),
+            title: title.to_string(),
+            description: description.to_string(),
+        };
+
+        let response = self.client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitLabConnectionError)?;
+
+        self.handle_response(response, "MR 생성 실패").await
+    }
+
+    pub async fn get_merge_request(&self, iid: u64) -> AppResult<MergeRequest> {
+        let url = format!("{}/merge_requests/{}", self.project_api_url, iid);
+
+        let response = self.client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitLabConnectionError)?;
+
+        self.handle_response(response, "MR 조회 실패").await
+    }
+
+    pub async fn find_merge_request_by_branch(&self, source_branch: &str) -> AppResult<Option<MergeRequest>> {
+        let url = format!("{}/merge_requests", self.project_api_url);
+
+        let response = self.client
+            .get(&url)
+            .query(&[
+                ("source_branch", source_branch),
+                ("state", "all"),
+                ("order_by", "created_at"),
+                ("sort", "desc"),
+            ])
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitLabConnectionError)?;
+
+        let merge_requests: Vec<MergeRequest> = self.handle_response(response, "MR 목록 조회 실패").await?;
+
+        Ok(merge_requests.into_iter().next())
+    }
+
+    pub async fn merge_merge_request(&self, iid: u64) -> AppResult<MergeRequest> {
+        let url = format!("{}/merge_requests/{}/merge", self.project_api_url, iid);
+
+        let response = self.client
+            .put(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::GitLabConnectionError)?;
+
+        self.handle_response(response, "MR 머지 실패").await
+    }
+}
+
+#[async_trait]
+impl CodeHost for GitLabClient {
+    fn name(&self) -> &'static str {
+        "GitLab"
+    }
+
+    fn change_request_label(&self) -> &'static str {
+        "MR"
+    }
+
+    fn change_request_name(&self) -> &'static str {
+        "Merge Request"
+    }
+
+    fn icon_url(&self) -> String {
+        format!("{}/favicon.ico", self.web_url)
+    }
+
+    fn branch_search_url(&self, branch: &str) -> String {
+        format!("{}/-/merge_requests?scope=all&state=all&source_branch={}", self.project_url, branch)
+    }
+
+    async fn create_change_request(
+        &self,
+        title: &str,
+        body: &str,
+        source_branch: &str,
+        target_branch: &str,
+    ) -> AppResult<ChangeRequest> {
+        let mr = self.create_merge_request(title, body, source_branch, target_branch).await?;
+        Ok(mr.into())
+    }
+
+    async fn find_change_request(&self, source_branch: &str) -> AppResult<Option<ChangeRequest>> {
+        let mr = self.find_merge_request_by_branch(source_branch).await?;
+        Ok(mr.map(ChangeRequest::from))
+    }
+
+    async fn get_change_request(&self, number: u64) -> AppResult<ChangeRequest> {
+        let mr = self.get_merge_request(number).await?;
+        Ok(mr.into())
+    }
+
+    async fn merge_change_request(&self, number: u64) -> AppResult<()> {
+        self.merge_merge_request(number).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,49 @@
+use crate::code_host::{ChangeRequest, ChangeRequestState};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Serialize)]
+pub struct CreateMergeRequestRequest {
+    pub source_branch: String,
+    pub target_branch: String,
+    pub title: String,
+    pub description: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MergeRequest {
+    pub id: u64,
+    // 프로젝트 안에서의 MR 번호 (!12)
+    pub iid: u64,
+    pub title: String,
+    pub description: Option<String>,
+    // opened, closed, locked, merged
+    pub state: String,
+    pub web_url: String,
+    pub source_branch: String,
+    pub target_branch: String,
+    pub merged_at: Option<String>,
+    // 소스 브랜치의 마지막 커밋
+    pub sha: Option<String>,
+}
+
+impl From<MergeRequest> for ChangeRequest {
+    fn from(mr: MergeRequest) -> Self {
+        let state = match mr.state.as_str() {
+            "merged" => ChangeRequestState::Merged,
+            "closed" => ChangeRequestState::Closed,
+            _ if mr.merged_at.is_some() => ChangeRequestState::Merged,
+            _ => ChangeRequestState::Open,
+        };
+
+        ChangeRequest {
+            number: mr.iid,
+            reference: format!("!{}", mr.iid),
+            title: mr.title,
+            url: mr.web_url,
+            source_branch: mr.source_branch,
+            target_branch: mr.target_branch,
+            state,
+            head_sha: mr.sha,
+        }
+    }
+}
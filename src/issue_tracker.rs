@@ -0,0 +1,74 @@
+use crate::{AppErrorType, AppResult};
+use crate::jira::{Comment, Issue, IssueUpdate, SearchResults, Transition, TransitionInput, TransitionPath, TransitionTarget, User, WorkflowConfig};
+use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
+use strum::EnumString;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
+pub enum IssueTrackerKind {
+    #[default]
+    Jira,
+    GitHub,
+}
+
+impl IssueTrackerKind {
+    pub fn is_jira(&self) -> bool {
+        *self == IssueTrackerKind::Jira
+    }
+}
+
+// Jira와 GitHub Issues를 같은 방식으로 다루기 위한 공통 인터페이스
+// 이슈는 Jira 모델(Issue, Status, Transition)을 공통 모델로 사용합니다
+#[async_trait]
+pub trait IssueTracker: Send + Sync {
+    // 메시지에 표시할 이름 (예: Jira)
+    fn name(&self) -> &'static str;
+
+    async fn get_issue(&self, issue_key: &str) -> AppResult<Issue>;
+
+    // 찾을 수 없는 티켓은 결과에서 빠지고, 그 외의 오류는 그대로 반환합니다
+    async fn get_issues(&self, issue_keys: &[String]) -> AppResult<Vec<Issue>> {
+        let mut issues = Vec::new();
+        for key in issue_keys {
+            match self.get_issue(key).await {
+                Ok(issue) => issues.push(issue),
+                Err(e) if matches!(e.error_type, AppErrorType::NotFound(_) | AppErrorType::JiraTicketNotFound(_)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(issues)
+    }
+
+    // 나에게 할당된 미완료 티켓
+    async fn search_assigned_issues(&self, limit: Option<usize>) -> AppResult<SearchResults>;
+
+    async fn search_issues_by_status(&self, status: &str, limit: Option<usize>) -> AppResult<SearchResults>;
+
+    async fn get_transitions(&self, issue_key: &str) -> AppResult<Vec<Transition>>;
+
+    async fn find_transition_path(
+        &self,
+        issue_key: &str,
+        workflow: &WorkflowConfig,
+        target: &TransitionTarget,
+    ) -> AppResult<TransitionPath>;
+
+    async fn apply_transition_path(
+        &self,
+        issue_key: &str,
+        path: &TransitionPath,
+        inputs: &[TransitionInput],
+    ) -> AppResult<()>;
+
+    async fn get_comments(&self, issue_key: &str) -> AppResult<Vec<Comment>>;
+
+    // 내가 작성한 코멘트 중 link_url로 연결된 코멘트가 있으면 수정하고, 없으면 Markdown 본문으로 새로 작성합니다
+    async fn upsert_comment(&self, issue_key: &str, link_url: &str, body: &str) -> AppResult<()>;
+
+    async fn get_myself(&self) -> AppResult<User>;
+
+    // 담당자 지정, 라벨 추가 등 IssueUpdate로 표현한 변경사항
+    async fn update_issue(&self, issue_key: &str, update: &IssueUpdate) -> AppResult<()>;
+}
@@ -0,0 +1,1139 @@
+use chrono::DateTime;
+use colored::Colorize;
+use serde_json::{json, Map, Value};
+
+// Atlassian Document Format(ADF) <-> Markdown / 터미널 출력 변환
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Markdown,
+    Terminal,
+    // Jira Server(API v2) 텍스트 필드용 위키 마크업
+    Wiki,
+}
+
+// Jira Server(API v2)는 ADF 대신 일반 텍스트를 돌려주므로 그대로 사용합니다
+pub fn to_markdown(doc: &Value) -> String {
+    match doc.as_str() {
+        Some(text) => text.to_string(),
+        None => render_blocks(children(doc), Format::Markdown, "\n\n"),
+    }
+}
+
+pub fn to_terminal(doc: &Value) -> String {
+    match doc.as_str() {
+        Some(text) => text.to_string(),
+        None => render_blocks(children(doc), Format::Terminal, "\n\n"),
+    }
+}
+
+pub fn to_wiki(doc: &Value) -> String {
+    match doc.as_str() {
+        Some(text) => text.to_string(),
+        None => render_blocks(children(doc), Format::Wiki, "\n\n"),
+    }
+}
+
+// 요청 본문 안의 ADF 문서를 모두 위키 마크업 텍스트로 바꿉니다 (Jira Server용)
+pub fn docs_to_text(value: Value) -> Value {
+    match value {
+        Value::Object(ref map) if map.get("type").and_then(Value::as_str) == Some("doc") => {
+            Value::String(to_wiki(&value))
+        }
+        Value::Object(map) => Value::Object(map.into_iter().map(|(key, value)| (key, docs_to_text(value))).collect()),
+        Value::Array(values) => Value::Array(values.into_iter().map(docs_to_text).collect()),
+        value => value,
+    }
+}
+
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '`' | '[' | ']' | '~' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    
+    escaped
+}
+
+fn escape_wiki(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    
+    for c in text.chars() {
+        if matches!(c, '\\' | '*' | '_' | '[' | ']' | '{' | '}' | '|') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    
+    escaped
+}
+
+// 문서에 href가 정확히 일치하는 링크가 있는지 확인합니다
+// Jira Server의 문자열 본문은 위키 링크([제목|url], [url])와 Markdown 링크를 확인합니다
+pub fn has_link(doc: &Value, href: &str) -> bool {
+    if let Some(text) = doc.as_str() {
+        return [format!("|{}]", href), format!("[{}]", href), format!("]({})", href)]
+            .iter()
+            .any(|pattern| text.contains(pattern.as_str()));
+    }
+    
+    let marked = doc
+        .get("marks")
+        .and_then(Value::as_array)
+        .is_some_and(|marks| marks.iter().any(|mark| node_type(mark) == "link" && attr_str(mark, "href") == Some(href)));
+    let card = node_type(doc) == "inlineCard" && attr_str(doc, "url") == Some(href);
+    
+    marked || card || children(doc).iter().any(|child| has_link(child, href))
+}
+
+fn children(node: &Value) -> &[Value] {
+    node.get("content")
+        .and_then(Value::as_array)
+        .map(Vec::as_slice)
+        .unwrap_or_default()
+}
+
+fn node_type(node: &Value) -> &str {
+    node.get("type").and_then(Value::as_str).unwrap_or_default()
+}
+
+fn attr<'a>(node: &'a Value, name: &str) -> Option<&'a Value> {
+    node.get("attrs").and_then(|attrs| attrs.get(name))
+}
+
+fn attr_str<'a>(node: &'a Value, name: &str) -> Option<&'a str> {
+    attr(node, name).and_then(Value::as_str)
+}
+
+fn is_inline(node: &Value) -> bool {
+    matches!(
+        node_type(node),
+        "text" | "hardBreak" | "mention" | "emoji" | "inlineCard" | "date" | "status" | "placeholder"
+    )
+}
+
+fn prefix_lines(text: &str, first: &str, rest: &str) -> String {
+    text.lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let prefix = if index == 0 { first } else { rest };
+            if line.is_empty() { prefix.trim_end().to_string() } else { format!("{}{}", prefix, line) }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_blocks(nodes: &[Value], format: Format, separator: &str) -> String {
+    // 블록 안에 인라인 노드가 바로 오는 경우(taskItem 등)는 한 줄로 이어서 출력
+    if nodes.iter().all(is_inline) {
+        return render_inline(nodes, format);
+    }
+    
+    nodes
+        .iter()
+        .map(|node| render_block(node, format))
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<_>>()
+        .join(separator)
+}
+
+fn render_block(node: &Value, format: Format) -> String {
+    match node_type(node) {
+        "paragraph" => render_inline(children(node), format),
+        "heading" => {
+            let level = attr(node, "level").and_then(Value::as_u64).unwrap_or(1).clamp(1, 6) as usize;
+            let text = render_inline(children(node), format);
+            match format {
+                Format::Markdown => format!("{} {}", "#".repeat(level), text),
+                Format::Terminal => text.bold().underline().to_string(),
+                Format::Wiki => format!("h{}. {}", level, text),
+            }
+        }
+        "bulletList" | "orderedList" | "taskList" => render_list(node, format),
+        "codeBlock" => {
+            let code: String = children(node)
+                .iter()
+                .filter_map(|text| text.get("text").and_then(Value::as_str))
+                .collect();
+            let language = attr_str(node, "language").unwrap_or_default();
+            match format {
+                Format::Markdown => format!("```{}\n{}\n```", language, code.trim_end_matches('\n')),
+                Format::Terminal => code.lines()
+                    .map(|line| format!("{} {}", "│".dimmed(), line.cyan()))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+                Format::Wiki if language.is_empty() => format!("{{code}}\n{}\n{{code}}", code.trim_end_matches('\n')),
+                Format::Wiki => format!("{{code:{}}}\n{}\n{{code}}", language, code.trim_end_matches('\n')),
+            }
+        }
+        "blockquote" => {
+            let content = render_blocks(children(node), format, "\n\n");
+            match format {
+                Format::Markdown => prefix_lines(&content, "> ", "> "),
+                Format::Terminal => {
+                    let bar = "│ ".dimmed().to_string();
+                    prefix_lines(&content, &bar, &bar)
+                }
+                Format::Wiki => format!("{{quote}}\n{}\n{{quote}}", content),
+            }
+        }
+        "rule" => match format {
+            Format::Markdown => "---".to_string(),
+            Format::Terminal => "─".repeat(40).dimmed().to_string(),
+            Format::Wiki => "----".to_string(),
+        },
+        "panel" => render_panel(node, format),
+        "table" => render_table(node, format),
+        "expand" | "nestedExpand" => {
+            let title = attr_str(node, "title").filter(|title| !title.is_empty()).unwrap_or("더보기");
+            let content = render_blocks(children(node), format, "\n\n");
+            match format {
+                Format::Markdown => format!("<details>\n<summary>{}</summary>\n\n{}\n\n</details>", title, content),
+                Format::Terminal => format!("{} {}\n{}", "▸".dimmed(), title.bold(), prefix_lines(&content, "  ", "  ")),
+                Format::Wiki => format!("*{}*\n{}", escape_wiki(title), content),
+            }
+        }
+        "mediaSingle" | "mediaGroup" => {
+            let names: Vec<String> = children(node)
+                .iter()
+                .map(|media| attr_str(media, "alt").unwrap_or("첨부파일").to_string())
+                .collect();
+            format!("📎 {}", names.join(", "))
+        }
+        "blockCard" | "embedCard" => {
+            let url = attr_str(node, "url").unwrap_or_default();
+            match format {
+                Format::Markdown => format!("<{}>", url),
+                Format::Terminal => url.underline().to_string(),
+                Format::Wiki => format!("[{}]", url),
+            }
+        }
+        _ if is_inline(node) => render_inline(std::slice::from_ref(node), format),
+        _ => render_blocks(children(node), format, "\n\n"),
+    }
+}
+
+fn render_list(node: &Value, format: Format) -> String {
+    if format == Format::Wiki {
+        return render_wiki_list(node);
+    }
+    
+    let start = attr(node, "order").and_then(Value::as_u64).unwrap_or(1);
+    let list_type = node_type(node);
+    
+    children(node)
+        .iter()
+        .enumerate()
+        .map(|(index, item)| {
+            let marker = match (list_type, format) {
+                ("orderedList", _) => format!("{}. ", start + index as u64),
+                ("taskList", Format::Markdown) if attr_str(item, "state") == Some("DONE") => "- [x] ".to_string(),
+                ("taskList", Format::Markdown) => "- [ ] ".to_string(),
+                ("taskList", Format::Terminal) if attr_str(item, "state") == Some("DONE") => "☑ ".to_string(),
+                ("taskList", Format::Terminal) => "☐ ".to_string(),
+                (_, Format::Markdown) => "- ".to_string(),
+                (_, _) => "• ".to_string(),
+            };
+            let content = render_blocks(children(item), format, "\n");
+            let indent = " ".repeat(marker.chars().count());
+            prefix_lines(&content, &marker, &indent)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// 위키 마크업은 들여쓰기 대신 "**", "#*"처럼 기호를 이어 붙여 중첩 목록을 표현합니다
+fn render_wiki_list(node: &Value) -> String {
+    let symbol = if node_type(node) == "orderedList" { "#" } else { "*" };
+    
+    children(node)
+        .iter()
+        .map(|item| {
+            let checkbox = match (node_type(node), attr_str(item, "state")) {
+                ("taskList", Some("DONE")) => "(/) ",
+                ("taskList", _) => "(x) ",
+                _ => "",
+            };
+            render_blocks(children(item), Format::Wiki, "\n")
+                .lines()
+                .enumerate()
+                .map(|(index, line)| match line.chars().next() {
+                    Some('*' | '#') if index > 0 && line.trim_start_matches(['*', '#']).starts_with(' ') => format!("{}{}", symbol, line),
+                    _ if index == 0 => format!("{} {}{}", symbol, checkbox, line),
+                    _ => line.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn render_panel(node: &Value, format: Format) -> String {
+    let panel_type = attr_str(node, "panelType").unwrap_or("info");
+    let content = render_blocks(children(node), format, "\n\n");
+    
+    match format {
+        Format::Markdown => {
+            let alert = match panel_type {
+                "success" => "TIP",
+                "warning" => "WARNING",
+                "error" => "CAUTION",
+                "note" => "IMPORTANT",
+                _ => "NOTE",
+            };
+            format!("> [!{}]\n{}", alert, prefix_lines(&content, "> ", "> "))
+        }
+        Format::Terminal => {
+            let (icon, bar) = match panel_type {
+                "success" => ("✅", "┃ ".green()),
+                "warning" => ("⚠️", "┃ ".yellow()),
+                "error" => ("❌", "┃ ".red()),
+                "note" => ("📝", "┃ ".purple()),
+                _ => ("💡", "┃ ".blue()),
+            };
+            let bar = bar.to_string();
+            prefix_lines(&content, &format!("{}{} ", bar, icon), &bar)
+        }
+        Format::Wiki => {
+            let macro_name = match panel_type {
+                "success" => "tip",
+                "warning" | "error" => "warning",
+                "note" => "note",
+                _ => "info",
+            };
+            format!("{{{0}}}\n{1}\n{{{0}}}", macro_name, content)
+        }
+    }
+}
+
+fn render_table(node: &Value, format: Format) -> String {
+    let rows: Vec<Vec<String>> = children(node)
+        .iter()
+        .map(|row| {
+            children(row)
+                .iter()
+                .map(|cell| {
+                    let text = render_blocks(children(cell), format, "\n");
+                    match format {
+                        Format::Markdown => text.replace('\n', "<br>"),
+                        Format::Terminal => text.replace('\n', " "),
+                        Format::Wiki => text.replace('\n', " \\\\ "),
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    
+    let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+    if columns == 0 {
+        return String::new();
+    }
+    
+    let cell = |row: &Vec<String>, column: usize| row.get(column).cloned().unwrap_or_default();
+    
+    match format {
+        Format::Markdown => {
+            let mut lines = Vec::new();
+            for (index, row) in rows.iter().enumerate() {
+                let cells: Vec<String> = (0..columns).map(|column| cell(row, column)).collect();
+                lines.push(format!("| {} |", cells.join(" | ")));
+                if index == 0 {
+                    lines.push(format!("|{}", " --- |".repeat(columns)));
+                }
+            }
+            lines.join("\n")
+        }
+        Format::Wiki => rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let separator = if index == 0 { "||" } else { "|" };
+                let cells: Vec<String> = (0..columns).map(|column| cell(row, column)).collect();
+                format!("{0}{1}{0}", separator, cells.join(separator))
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        Format::Terminal => {
+            let widths: Vec<usize> = (0..columns)
+                .map(|column| {
+                    rows.iter()
+                        .map(|row| console::measure_text_width(&cell(row, column)))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .collect();
+            
+            let mut lines = Vec::new();
+            for (index, row) in rows.iter().enumerate() {
+                let cells: Vec<String> = (0..columns)
+                    .map(|column| {
+                        let text = cell(row, column);
+                        let padding = widths[column] - console::measure_text_width(&text);
+                        let text = if index == 0 { text.bold().to_string() } else { text };
+                        format!("{}{}", text, " ".repeat(padding))
+                    })
+                    .collect();
+                lines.push(cells.join(&" │ ".dimmed().to_string()));
+                if index == 0 {
+                    let separator: Vec<String> = widths.iter().map(|width| "─".repeat(*width)).collect();
+                    lines.push(separator.join("─┼─").dimmed().to_string());
+                }
+            }
+            lines.join("\n")
+        }
+    }
+}
+
+fn render_inline(nodes: &[Value], format: Format) -> String {
+    nodes.iter().map(|node| render_inline_node(node, format)).collect()
+}
+
+fn render_inline_node(node: &Value, format: Format) -> String {
+    match node_type(node) {
+        "text" => render_text(node, format),
+        "hardBreak" => "\n".to_string(),
+        "mention" => {
+            let name = attr_str(node, "text")
+                .map(|text| text.trim_start_matches('@').to_string())
+                .or_else(|| attr_str(node, "id").map(str::to_string))
+                .unwrap_or_default();
+            // GitHub에서 다른 사용자가 멘션되지 않도록 코드로 감쌉니다
+            match format {
+                Format::Markdown => format!("`@{}`", name),
+                Format::Terminal => format!("@{}", name).blue().bold().to_string(),
+                Format::Wiki => format!("@{}", escape_wiki(&name)),
+            }
+        }
+        "emoji" => attr_str(node, "text")
+            .or_else(|| attr_str(node, "shortName"))
+            .unwrap_or_default()
+            .to_string(),
+        "inlineCard" => {
+            let url = attr_str(node, "url").unwrap_or_default();
+            match format {
+                Format::Markdown => format!("<{}>", url),
+                Format::Terminal => url.underline().to_string(),
+                Format::Wiki => format!("[{}]", url),
+            }
+        }
+        "date" => attr_str(node, "timestamp")
+            .and_then(|timestamp| timestamp.parse::<i64>().ok())
+            .and_then(DateTime::from_timestamp_millis)
+            .map(|date| date.format("%Y-%m-%d").to_string())
+            .unwrap_or_default(),
+        "status" => {
+            let text = attr_str(node, "text").unwrap_or_default();
+            match format {
+                Format::Markdown => format!("`{}`", text),
+                Format::Terminal => format!("[{}]", text).bold().to_string(),
+                Format::Wiki => format!("{{{{{}}}}}", text),
+            }
+        }
+        _ => render_inline(children(node), format),
+    }
+}
+
+fn render_text(node: &Value, format: Format) -> String {
+    let text = node.get("text").and_then(Value::as_str).unwrap_or_default();
+    let marks = node.get("marks").and_then(Value::as_array).map(Vec::as_slice).unwrap_or_default();
+    let has_mark = |name: &str| marks.iter().any(|mark| node_type(mark) == name);
+    let link = marks
+        .iter()
+        .find(|mark| node_type(mark) == "link")
+        .and_then(|mark| attr_str(mark, "href"));
+    
+    match format {
+        Format::Markdown => {
+            let mut rendered = if has_mark("code") {
+                format!("`{}`", text)
+            } else {
+                escape_markdown(text)
+            };
+            if has_mark("strong") {
+                rendered = format!("**{}**", rendered);
+            }
+            if has_mark("em") {
+                rendered = format!("*{}*", rendered);
+            }
+            if has_mark("strike") {
+                rendered = format!("~~{}~~", rendered);
+            }
+            if let Some(href) = link {
+                rendered = format!("[{}]({})", rendered, href);
+            }
+            rendered
+        }
+        Format::Terminal => {
+            let mut rendered = text.normal();
+            if has_mark("code") {
+                rendered = rendered.cyan();
+            }
+            if has_mark("strong") {
+                rendered = rendered.bold();
+            }
+            if has_mark("em") {
+                rendered = rendered.italic();
+            }
+            if has_mark("strike") {
+                rendered = rendered.strikethrough();
+            }
+            if has_mark("underline") {
+                rendered = rendered.underline();
+            }
+            
+            match link {
+                Some(href) if href == text => href.blue().underline().to_string(),
+                Some(href) => format!("{} ({})", rendered.underline(), href.dimmed()),
+                None => rendered.to_string(),
+            }
+        }
+        Format::Wiki => {
+            let mut rendered = if has_mark("code") {
+                format!("{{{{{}}}}}", text)
+            } else {
+                escape_wiki(text)
+            };
+            if has_mark("strong") {
+                rendered = format!("*{}*", rendered);
+            }
+            if has_mark("em") {
+                rendered = format!("_{}_", rendered);
+            }
+            if has_mark("strike") {
+                rendered = format!("-{}-", rendered);
+            }
+            if has_mark("underline") {
+                rendered = format!("+{}+", rendered);
+            }
+            match link {
+                Some(href) if href == text => format!("[{}]", href),
+                Some(href) => format!("[{}|{}]", rendered, href),
+                None => rendered,
+            }
+        }
+    }
+}
+
+pub fn from_markdown(markdown: &str) -> Value {
+    let lines: Vec<&str> = markdown.lines().collect();
+    json!({ "type": "doc", "version": 1, "content": parse_blocks(&lines) })
+}
+
+fn is_rule(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.len() >= 3
+        && ["-", "*", "_"].iter().any(|c| trimmed.chars().all(|ch| ch.to_string() == *c || ch == ' '))
+        && trimmed.chars().filter(|ch| !ch.is_whitespace()).count() >= 3
+}
+
+fn heading_level(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|c| *c == '#').count();
+    let rest = &line[level..];
+    ((1..=6).contains(&level) && (rest.is_empty() || rest.starts_with(' '))).then(|| (level, rest.trim()))
+}
+
+// (들여쓰기, 순서 목록 시작 번호, 체크 상태, 내용)
+fn list_marker(line: &str) -> Option<(usize, Option<u64>, Option<bool>, &str)> {
+    let indent = line.len() - line.trim_start().len();
+    let rest = line.trim_start();
+    
+    let (order, content) = if let Some(content) = rest.strip_prefix("- ").or_else(|| rest.strip_prefix("* ")).or_else(|| rest.strip_prefix("+ ")) {
+        (None, content)
+    } else {
+        let digits = rest.chars().take_while(char::is_ascii_digit).count();
+        let after = &rest[digits..];
+        if digits == 0 || digits > 9 || !(after.starts_with(". ") || after.starts_with(") ")) {
+            return None;
+        }
+        (rest[..digits].parse().ok(), &after[2..])
+    };
+    
+    let (checked, content) = match content {
+        _ if order.is_some() => (None, content),
+        _ if content.starts_with("[ ] ") => (Some(false), &content[4..]),
+        _ if content.starts_with("[x] ") || content.starts_with("[X] ") => (Some(true), &content[4..]),
+        _ => (None, content),
+    };
+    
+    Some((indent, order, checked, content))
+}
+
+fn is_table_separator(line: &str) -> bool {
+    let trimmed = line.trim();
+    trimmed.contains('-')
+        && trimmed.contains('|')
+        && trimmed.chars().all(|c| matches!(c, '|' | '-' | ':' | ' '))
+}
+
+fn split_table_row(line: &str) -> Vec<String> {
+    let trimmed = line.trim().trim_start_matches('|');
+    let trimmed = trimmed.strip_suffix('|').filter(|_| !trimmed.ends_with("\\|")).unwrap_or(trimmed);
+    
+    let mut cells = Vec::new();
+    let mut current = String::new();
+    let mut escaped = false;
+    for c in trimmed.chars() {
+        match c {
+            '|' if !escaped => cells.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+        escaped = c == '\\' && !escaped;
+    }
+    cells.push(current);
+    
+    cells.into_iter().map(|cell| cell.trim().to_string()).collect()
+}
+
+fn starts_block(lines: &[&str], index: usize) -> bool {
+    let line = lines[index];
+    let trimmed = line.trim_start();
+    
+    trimmed.starts_with("```")
+        || trimmed.starts_with('>')
+        || heading_level(trimmed).is_some()
+        || is_rule(line)
+        || list_marker(line).is_some()
+        || (trimmed.starts_with('|') && lines.get(index + 1).is_some_and(|next| is_table_separator(next)))
+}
+
+fn paragraph(text: &str) -> Value {
+    json!({ "type": "paragraph", "content": parse_inline(text) })
+}
+
+fn parse_blocks(lines: &[&str]) -> Vec<Value> {
+    let mut blocks = Vec::new();
+    let mut index = 0;
+    
+    while index < lines.len() {
+        let line = lines[index];
+        let trimmed = line.trim_start();
+        
+        if trimmed.is_empty() {
+            index += 1;
+        } else if let Some(fence) = trimmed.strip_prefix("```") {
+            let language = fence.trim();
+            let mut code = Vec::new();
+            index += 1;
+            while index < lines.len() && !lines[index].trim_start().starts_with("```") {
+                code.push(lines[index]);
+                index += 1;
+            }
+            index += 1;
+            
+            let mut block = json!({ "type": "codeBlock", "content": [] });
+            if !language.is_empty() {
+                block["attrs"] = json!({ "language": language });
+            }
+            if !code.is_empty() {
+                block["content"] = json!([{ "type": "text", "text": code.join("\n") }]);
+            }
+            blocks.push(block);
+        } else if let Some((level, text)) = heading_level(trimmed) {
+            blocks.push(json!({ "type": "heading", "attrs": { "level": level }, "content": parse_inline(text) }));
+            index += 1;
+        } else if is_rule(line) {
+            blocks.push(json!({ "type": "rule" }));
+            index += 1;
+        } else if trimmed.starts_with('>') {
+            let mut quoted = Vec::new();
+            while index < lines.len() && lines[index].trim_start().starts_with('>') {
+                let content = lines[index].trim_start().trim_start_matches('>');
+                quoted.push(content.strip_prefix(' ').unwrap_or(content));
+                index += 1;
+            }
+            blocks.push(parse_quote(&quoted));
+        } else if trimmed.starts_with('|') && lines.get(index + 1).is_some_and(|next| is_table_separator(next)) {
+            let mut rows = vec![split_table_row(line)];
+            index += 2;
+            while index < lines.len() && lines[index].trim_start().starts_with('|') {
+                rows.push(split_table_row(lines[index]));
+                index += 1;
+            }
+            blocks.push(build_table(&rows));
+        } else if list_marker(line).is_some() {
+            let (list, next) = parse_list(lines, index);
+            blocks.push(list);
+            index = next;
+        } else {
+            let mut paragraph_lines = vec![trimmed];
+            index += 1;
+            while index < lines.len() && !lines[index].trim().is_empty() && !starts_block(lines, index) {
+                paragraph_lines.push(lines[index].trim_start());
+                index += 1;
+            }
+            blocks.push(paragraph(&paragraph_lines.join("\n")));
+        }
+    }
+    
+    blocks
+}
+
+fn parse_quote(lines: &[&str]) -> Value {
+    let panel_type = lines.first().and_then(|first| match first.trim() {
+        "[!NOTE]" => Some("info"),
+        "[!TIP]" => Some("success"),
+        "[!IMPORTANT]" => Some("note"),
+        "[!WARNING]" => Some("warning"),
+        "[!CAUTION]" => Some("error"),
+        _ => None,
+    });
+    
+    match panel_type {
+        Some(panel_type) => json!({
+            "type": "panel",
+            "attrs": { "panelType": panel_type },
+            "content": parse_blocks(&lines[1..]),
+        }),
+        None => json!({ "type": "blockquote", "content": parse_blocks(lines) }),
+    }
+}
+
+fn build_table(rows: &[Vec<String>]) -> Value {
+    let rows: Vec<Value> = rows
+        .iter()
+        .enumerate()
+        .map(|(index, cells)| {
+            let cell_type = if index == 0 { "tableHeader" } else { "tableCell" };
+            let cells: Vec<Value> = cells
+                .iter()
+                .map(|cell| json!({
+                    "type": cell_type,
+                    "content": [paragraph(&cell.replace("<br>", "\n"))],
+                }))
+                .collect();
+            json!({ "type": "tableRow", "content": cells })
+        })
+        .collect();
+    
+    json!({ "type": "table", "content": rows })
+}
+
+// 같은 들여쓰기의 항목을 모으고, 더 깊게 들여쓴 줄은 항목 안의 블록(하위 목록 등)으로 파싱합니다
+fn parse_list(lines: &[&str], start: usize) -> (Value, usize) {
+    let (indent, order, checked, _) = list_marker(lines[start]).unwrap();
+    let list_type = match (order, checked) {
+        (Some(_), _) => "orderedList",
+        (None, Some(_)) => "taskList",
+        (None, None) => "bulletList",
+    };
+    
+    let mut items = Vec::new();
+    let mut index = start;
+    
+    while index < lines.len() {
+        let Some((item_indent, item_order, item_checked, content)) = list_marker(lines[index]) else {
+            break;
+        };
+        if item_indent != indent || item_order.is_some() != order.is_some() || item_checked.is_some() != checked.is_some() {
+            break;
+        }
+        
+        let mut item_lines = vec![content.to_string()];
+        index += 1;
+        while index < lines.len() {
+            let line = lines[index];
+            let line_indent = line.len() - line.trim_start().len();
+            if line.trim().is_empty() {
+                let continues = lines.get(index + 1).is_some_and(|next| {
+                    !next.trim().is_empty() && next.len() - next.trim_start().len() > indent
+                });
+                if !continues {
+                    break;
+                }
+            } else if line_indent <= indent
+                && (starts_block(lines, index) || item_lines.last().is_some_and(|last| last.trim().is_empty())) {
+                break;
+            }
+            item_lines.push(line.get(indent + 2..).map(str::to_string).unwrap_or_else(|| line.trim_start().to_string()));
+            index += 1;
+        }
+        
+        let item_refs: Vec<&str> = item_lines.iter().map(String::as_str).collect();
+        let item = match item_checked {
+            Some(done) => json!({
+                "type": "taskItem",
+                "attrs": { "localId": items.len().to_string(), "state": if done { "DONE" } else { "TODO" } },
+                "content": parse_inline(&item_lines.join("\n")),
+            }),
+            None => json!({ "type": "listItem", "content": parse_blocks(&item_refs) }),
+        };
+        items.push(item);
+    }
+    
+    let mut list = json!({ "type": list_type, "content": items });
+    match list_type {
+        "orderedList" => list["attrs"] = json!({ "order": order.unwrap_or(1) }),
+        "taskList" => list["attrs"] = json!({ "localId": "" }),
+        _ => {}
+    }
+    
+    (list, index)
+}
+
+fn push_text(nodes: &mut Vec<Value>, text: &str, marks: &[Value]) {
+    if text.is_empty() {
+        return;
+    }
+    
+    if let Some(last) = nodes.last_mut()
+        && last["type"] == "text"
+        && last.get("marks").map(Value::as_array).unwrap_or_default().map(Vec::as_slice).unwrap_or_default() == marks
+        && let Some(existing) = last["text"].as_str() {
+        last["text"] = Value::String(format!("{}{}", existing, text));
+        return;
+    }
+    
+    let mut node = Map::new();
+    node.insert("type".to_string(), json!("text"));
+    node.insert("text".to_string(), json!(text));
+    if !marks.is_empty() {
+        node.insert("marks".to_string(), Value::Array(marks.to_vec()));
+    }
+    nodes.push(Value::Object(node));
+}
+
+fn find_closing(chars: &[char], from: usize, delimiter: &[char]) -> Option<usize> {
+    let mut index = from;
+    while index + delimiter.len() <= chars.len() {
+        if chars[index] == '\\' {
+            index += 2;
+            continue;
+        }
+        if chars[index..index + delimiter.len()] == *delimiter && index > from {
+            return Some(index);
+        }
+        index += 1;
+    }
+    None
+}
+
+pub fn parse_inline(text: &str) -> Vec<Value> {
+    let mut nodes = Vec::new();
+    parse_inline_with(&text.chars().collect::<Vec<_>>(), &[], &mut nodes);
+    nodes
+}
+
+fn with_mark(marks: &[Value], mark: Value) -> Vec<Value> {
+    let mut marks = marks.to_vec();
+    marks.push(mark);
+    marks
+}
+
+fn parse_inline_with(chars: &[char], marks: &[Value], nodes: &mut Vec<Value>) {
+    let mut buffer = String::new();
+    let mut index = 0;
+    
+    macro_rules! flush {
+        () => {
+            push_text(nodes, &std::mem::take(&mut buffer), marks)
+        };
+    }
+    
+    while index < chars.len() {
+        let c = chars[index];
+        let next = chars.get(index + 1).copied();
+        
+        match c {
+            '\\' if next.is_some_and(|next| next.is_ascii_punctuation()) => {
+                buffer.push(next.unwrap());
+                index += 2;
+            }
+            '\n' => {
+                flush!();
+                nodes.push(json!({ "type": "hardBreak" }));
+                index += 1;
+            }
+            '`' => match chars[index + 1..].iter().position(|c| *c == '`') {
+                Some(length) if length > 0 => {
+                    flush!();
+                    let code: String = chars[index + 1..index + 1 + length].iter().collect();
+                    let link_marks: Vec<Value> = marks.iter().filter(|mark| mark["type"] == "link").cloned().collect();
+                    push_text(nodes, &code, &with_mark(&link_marks, json!({ "type": "code" })));
+                    index += length + 2;
+                }
+                _ => {
+                    buffer.push(c);
+                    index += 1;
+                }
+            },
+            '[' => {
+                let closing = find_closing(chars, index + 1, &[']']);
+                let url_end = closing
+                    .filter(|closing| chars.get(closing + 1) == Some(&'('))
+                    .and_then(|closing| chars[closing + 2..].iter().position(|c| *c == ')').map(|length| (closing, closing + 2 + length)));
+                
+                match url_end {
+                    Some((closing, end)) => {
+                        flush!();
+                        let href: String = chars[closing + 2..end].iter().collect();
+                        let link = json!({ "type": "link", "attrs": { "href": href.trim() } });
+                        parse_inline_with(&chars[index + 1..closing], &with_mark(marks, link), nodes);
+                        index = end + 1;
+                    }
+                    None => {
+                        buffer.push(c);
+                        index += 1;
+                    }
+                }
+            }
+            '<' => {
+                let end = chars[index + 1..].iter().position(|c| *c == '>' || c.is_whitespace());
+                let url: Option<String> = end
+                    .filter(|length| chars[index + 1 + length] == '>')
+                    .map(|length| chars[index + 1..index + 1 + length].iter().collect())
+                    .filter(|url: &String| url.starts_with("http://") || url.starts_with("https://"));
+                
+                match url {
+                    Some(url) => {
+                        flush!();
+                        let link = json!({ "type": "link", "attrs": { "href": url } });
+                        push_text(nodes, &url, &with_mark(marks, link));
+                        index += url.chars().count() + 2;
+                    }
+                    None => {
+                        buffer.push(c);
+                        index += 1;
+                    }
+                }
+            }
+            '*' | '_' | '~' => {
+                let double = next == Some(c);
+                let (delimiter, mark): (Vec<char>, &str) = match (c, double) {
+                    ('~', true) => (vec!['~', '~'], "strike"),
+                    ('~', false) => {
+                        buffer.push(c);
+                        index += 1;
+                        continue;
+                    }
+                    (_, true) => (vec![c, c], "strong"),
+                    (_, false) => (vec![c], "em"),
+                };
+                
+                // snake_case 처럼 단어 중간의 밑줄은 강조로 보지 않습니다
+                let inside_word = c == '_' && index > 0 && chars[index - 1].is_alphanumeric();
+                let start = index + delimiter.len();
+                let closing = find_closing(chars, start, &delimiter)
+                    .filter(|closing| !chars[start].is_whitespace() && !chars[closing - 1].is_whitespace());
+                
+                match closing {
+                    Some(closing) if !inside_word => {
+                        flush!();
+                        parse_inline_with(&chars[start..closing], &with_mark(marks, json!({ "type": mark })), nodes);
+                        index = closing + delimiter.len();
+                    }
+                    _ => {
+                        buffer.extend(&delimiter);
+                        index += delimiter.len();
+                    }
+                }
+            }
+            _ => {
+                buffer.push(c);
+                index += 1;
+            }
+        }
+    }
+    
+    flush!();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn text(value: &str) -> Value {
+        json!({ "type": "text", "text": value })
+    }
+    
+    #[test]
+    fn keeps_plain_text_descriptions() {
+        let description = json!("h2. 배경\n로그인 *버튼* 수정");
+        
+        assert_eq!(to_markdown(&description), "h2. 배경\n로그인 *버튼* 수정");
+        assert_eq!(to_terminal(&description), "h2. 배경\n로그인 *버튼* 수정");
+    }
+    
+    #[test]
+    fn replaces_nested_docs_with_text() {
+        let body = json!({
+            "fields": {
+                "summary": "제목",
+                "description": from_markdown("본문 **강조**"),
+            },
+            "update": { "comment": [{ "add": { "body": from_markdown("코멘트") } }] },
+        });
+        
+        assert_eq!(
+            docs_to_text(body),
+            json!({
+                "fields": { "summary": "제목", "description": "본문 *강조*" },
+                "update": { "comment": [{ "add": { "body": "코멘트" } }] },
+            })
+        );
+    }
+    
+    #[test]
+    fn renders_wiki_markup_for_server() {
+        let doc = from_markdown("## 배경\n\n**PR** [#12 로그인 수정](https://github.com/o/r/pull/12) 참고, `code` 와 *기울임*\n\n- 항목\n  - 하위\n\n1. 첫째");
+        
+        assert_eq!(
+            to_wiki(&doc),
+            "h2. 배경\n\n*PR* [#12 로그인 수정|https://github.com/o/r/pull/12] 참고, {{code}} 와 _기울임_\n\n* 항목\n** 하위\n\n# 첫째"
+        );
+    }
+    
+    #[test]
+    fn renders_wiki_links_code_blocks_and_escapes() {
+        let doc = json!({
+            "type": "doc",
+            "content": [
+                { "type": "paragraph", "content": [
+                    { "type": "text", "text": "https://example.com", "marks": [{ "type": "link", "attrs": { "href": "https://example.com" } }] },
+                    text(" a_b [x] {y}"),
+                ]},
+                { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [text("let a = 1;")] },
+            ]
+        });
+        
+        assert_eq!(
+            to_wiki(&doc),
+            "[https://example.com] a\\_b \\[x\\] \\{y\\}\n\n{code:rust}\nlet a = 1;\n{code}"
+        );
+        assert!(has_link(&docs_to_text(doc), "https://example.com"));
+    }
+    
+    #[test]
+    fn renders_paragraphs_and_marks_as_markdown() {
+        let doc = json!({
+            "type": "doc",
+            "version": 1,
+            "content": [
+                { "type": "paragraph", "content": [
+                    text("로그인 "),
+                    { "type": "text", "text": "버그", "marks": [{ "type": "strong" }] },
+                    text(" 수정 "),
+                    { "type": "text", "text": "문서", "marks": [{ "type": "link", "attrs": { "href": "https://example.com" } }] },
+                ]},
+                { "type": "paragraph", "content": [
+                    { "type": "mention", "attrs": { "id": "1", "text": "@김개발" } },
+                    text(" 확인 부탁드립니다"),
+                ]},
+            ]
+        });
+        
+        assert_eq!(
+            to_markdown(&doc),
+            "로그인 **버그** 수정 [문서](https://example.com)\n\n`@김개발` 확인 부탁드립니다"
+        );
+    }
+    
+    #[test]
+    fn renders_nested_lists_code_and_panels() {
+        let doc = json!({
+            "type": "doc",
+            "content": [
+                { "type": "bulletList", "content": [
+                    { "type": "listItem", "content": [
+                        { "type": "paragraph", "content": [text("첫 번째")] },
+                        { "type": "orderedList", "content": [
+                            { "type": "listItem", "content": [{ "type": "paragraph", "content": [text("하위")] }] },
+                        ]},
+                    ]},
+                    { "type": "listItem", "content": [{ "type": "paragraph", "content": [text("두 번째")] }] },
+                ]},
+                { "type": "codeBlock", "attrs": { "language": "rust" }, "content": [text("fn main() {}")] },
+                { "type": "panel", "attrs": { "panelType": "warning" }, "content": [
+                    { "type": "paragraph", "content": [text("주의")] },
+                ]},
+            ]
+        });
+        
+        assert_eq!(
+            to_markdown(&doc),
+            "- 첫 번째\n  1. 하위\n- 두 번째\n\n```rust\nfn main() {}\n```\n\n> [!WARNING]\n> 주의"
+        );
+    }
+    
+    #[test]
+    fn renders_tables_as_markdown() {
+        let cell = |cell_type: &str, value: &str| json!({
+            "type": cell_type,
+            "content": [{ "type": "paragraph", "content": [text(value)] }],
+        });
+        let doc = json!({
+            "type": "doc",
+            "content": [{ "type": "table", "content": [
+                { "type": "tableRow", "content": [cell("tableHeader", "항목"), cell("tableHeader", "값")] },
+                { "type": "tableRow", "content": [cell("tableCell", "a|b"), cell("tableCell", "1")] },
+            ]}]
+        });
+        
+        assert_eq!(to_markdown(&doc), "| 항목 | 값 |\n| --- | --- |\n| a\\|b | 1 |");
+    }
+    
+    #[test]
+    fn parses_markdown_into_adf() {
+        let doc = from_markdown("# 제목\n\n**굵게** 와 `code`, [링크](https://example.com)\n\n- 하나\n- 둘");
+        
+        assert_eq!(doc["content"][0], json!({ "type": "heading", "attrs": { "level": 1 }, "content": [text("제목")] }));
+        assert_eq!(
+            doc["content"][1]["content"],
+            json!([
+                { "type": "text", "text": "굵게", "marks": [{ "type": "strong" }] },
+                text(" 와 "),
+                { "type": "text", "text": "code", "marks": [{ "type": "code" }] },
+                text(", "),
+                { "type": "text", "text": "링크", "marks": [{ "type": "link", "attrs": { "href": "https://example.com" } }] },
+            ])
+        );
+        assert_eq!(doc["content"][2]["type"], "bulletList");
+        assert_eq!(doc["content"][2]["content"].as_array().unwrap().len(), 2);
+    }
+    
+    #[test]
+    fn keeps_snake_case_and_escapes_as_text() {
+        let doc = from_markdown("snake_case_name 과 \\*별표\\*");
+        
+        assert_eq!(doc["content"][0]["content"], json!([text("snake_case_name 과 *별표*")]));
+    }
+    
+    #[test]
+    fn round_trips_markdown() {
+        let markdown = "## 변경사항\n\n- 로그인 *오류* 수정\n  - ~~임시~~ 코드 제거\n- 배포\n\n- [x] 테스트\n- [ ] 문서\n\n1. 첫째\n2. 둘째\n\n> [!NOTE]\n> 배포 전 확인\n\n```sh\ncargo test\n```\n\n| 이름 | 값 |\n| --- | --- |\n| a | b |";
+        
+        assert_eq!(to_markdown(&from_markdown(markdown)), markdown);
+    }
+    
+    #[test]
+    fn has_link_matches_exact_href_only() {
+        let doc = json!({
+            "type": "doc",
+            "content": [{
+                "type": "paragraph",
+                "content": [
+                    { "type": "text", "text": "PR", "marks": [{ "type": "link", "attrs": { "href": "https://github.com/o/r/pull/12" } }] },
+                    { "type": "text", "text": " https://github.com/o/r/pull/1 참고" }
+                ]
+            }]
+        });
+        
+        assert!(has_link(&doc, "https://github.com/o/r/pull/12"));
+        assert!(!has_link(&doc, "https://github.com/o/r/pull/1"));
+        assert!(!has_link(&doc, "https://github.com/o/r/pull/123"));
+    }
+    
+    #[test]
+    fn has_link_checks_inline_cards_and_server_text() {
+        let card = json!({ "type": "doc", "content": [{ "type": "paragraph", "content": [{ "type": "inlineCard", "attrs": { "url": "https://x/pull/3" } }] }] });
+        assert!(has_link(&card, "https://x/pull/3"));
+        
+        assert!(has_link(&json!("PR: [#3|https://x/pull/3]"), "https://x/pull/3"));
+        assert!(has_link(&json!("PR: [https://x/pull/3]"), "https://x/pull/3"));
+        assert!(!has_link(&json!("PR: [#3|https://x/pull/31]"), "https://x/pull/3"));
+    }
+}
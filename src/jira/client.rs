@@ -1,26 +1,88 @@
 use crate::error::{AppError, AppErrorType, AppResult, AppErrorExt};
+use crate::issue_tracker::IssueTracker;
+use super::adf;
+use super::jql::{Clause, Jql, JqlValue, SortOrder};
 use super::models::*;
+use super::workflow::{TransitionPath, TransitionTarget, WorkflowConfig, WorkflowStage};
+use async_trait::async_trait;
 use base64::Engine;
+use chrono::{DateTime, Utc};
+use futures::stream::{self, Stream, TryStreamExt};
 use reqwest::{header, Client, Response};
-use serde_json::json;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::collections::{HashSet, VecDeque};
+use strum::EnumString;
+
+const SEARCH_FIELDS: [&str; 13] = [
+    "summary", "description", "status", "priority", 
+    "assignee", "reporter", "created", "updated",
+    "issuetype", "project", "parent", "subtasks",
+    "issuelinks",
+];
+
+// /search/jql은 fields를 지정하면 페이지당 최대 100개까지 반환합니다
+const SEARCH_PAGE_SIZE: usize = 100;
+
+// 전환 경로 탐색 시 최대 단계 수
+const MAX_TRANSITION_STEPS: usize = 6;
+
+// cloud: Basic 인증(이메일 + API 토큰), REST API v3 (ADF)
+// server: Bearer 인증(개인 액세스 토큰), REST API v2 (일반 텍스트) - Jira Server / Data Center
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, EnumString)]
+#[serde(rename_all = "lowercase")]
+#[strum(ascii_case_insensitive)]
+pub enum JiraFlavor {
+    #[default]
+    #[strum(serialize = "cloud")]
+    Cloud,
+    #[serde(alias = "datacenter")]
+    #[strum(serialize = "server", serialize = "datacenter")]
+    Server,
+}
+
+impl JiraFlavor {
+    pub fn is_cloud(&self) -> bool {
+        *self == JiraFlavor::Cloud
+    }
+    
+    pub fn api_version(&self) -> u8 {
+        match self {
+            JiraFlavor::Cloud => 3,
+            JiraFlavor::Server => 2,
+        }
+    }
+}
 
 pub struct JiraClient {
     pub base_url: String,
     pub username: String,
     pub token: String,
     pub project_key: String,
+    pub flavor: JiraFlavor,
     client: Client,
 }
 
 impl JiraClient {
-    pub fn new(base_url: String, username: String, token: String, project_key: String) -> AppResult<Self> {
-        let auth_header = format!("{}:{}", username, token);
-        let encoded_auth = base64::engine::general_purpose::STANDARD.encode(auth_header);
+    pub fn new(
+        base_url: String,
+        username: String,
+        token: String,
+        project_key: String,
+        flavor: JiraFlavor,
+    ) -> AppResult<Self> {
+        let auth_value = match flavor {
+            JiraFlavor::Cloud => {
+                let auth_header = format!("{}:{}", username, token);
+                format!("Basic {}", base64::engine::general_purpose::STANDARD.encode(auth_header))
+            }
+            JiraFlavor::Server => format!("Bearer {}", token),
+        };
         
         let mut headers = header::HeaderMap::new();
         headers.insert(
             header::AUTHORIZATION,
-            header::HeaderValue::from_str(&format!("Basic {}", encoded_auth))
+            header::HeaderValue::from_str(&auth_value)
                 .map_err(|e| AppError::jira_api_error(format!("인증 헤더 생성 실패: {}", e)))?,
         );
         headers.insert(
@@ -38,10 +100,24 @@ impl JiraClient {
             username,
             token,
             project_key,
+            flavor,
             client,
         })
     }
     
+    fn api_url(&self) -> String {
+        format!("{}/rest/api/{}", self.base_url, self.flavor.api_version())
+    }
+    
+    // Server(API v2)는 설명/코멘트 등 텍스트 필드에 ADF 대신 문자열을 받습니다
+    fn request_body<T: Serialize>(&self, body: &T) -> Value {
+        let value = serde_json::to_value(body).unwrap_or_default();
+        match self.flavor {
+            JiraFlavor::Cloud => value,
+            JiraFlavor::Server => adf::docs_to_text(value),
+        }
+    }
+    
     async fn handle_response<T: for<'de> serde::Deserialize<'de>>(
         &self, 
         response: Response,
@@ -61,6 +137,11 @@ impl JiraClient {
                 reqwest::StatusCode::NOT_FOUND => {
                     Err(AppError::new(AppErrorType::JiraTicketNotFound(format!("{}: 리소스를 찾을 수 없음", context))))
                 }
+                reqwest::StatusCode::BAD_REQUEST => {
+                    let errors: JiraErrorResponse = serde_json::from_str(&error_text).unwrap_or_default();
+                    let message = if errors.is_empty() { error_text } else { errors.format() };
+                    Err(AppError::new(AppErrorType::JiraBadRequest(format!("{}: {} - {}", context, status, message))))
+                }
                 _ => {
                     Err(AppError::jira_api_error(format!("{}: {} - {}", context, status, error_text)))
                 }
@@ -74,7 +155,7 @@ impl JiraClient {
     }
     
     pub async fn test_connection(&self) -> AppResult<()> {
-        let url = format!("{}/rest/api/3/myself", self.base_url);
+        let url = format!("{}/myself", self.api_url());
         
         let response = self
             .client
@@ -92,8 +173,89 @@ impl JiraClient {
         }
     }
     
+    pub async fn get_myself(&self) -> AppResult<User> {
+        let url = format!("{}/myself", self.api_url());
+        
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "사용자 정보 조회").await
+    }
+    
+    pub async fn update_issue(&self, issue_key: &str, update: &IssueUpdate) -> AppResult<()> {
+        let url = format!("{}/issue/{}", self.api_url(), issue_key);
+        
+        let response = self
+            .client
+            .put(&url)
+            .json(&self.request_body(update))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        if response.status().is_success() {
+            return Ok(());
+        }
+        
+        let status = response.status();
+        let error_text = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "응답 읽기 실패".to_string());
+        let errors: JiraErrorResponse = serde_json::from_str(&error_text).unwrap_or_default();
+        let message = if errors.is_empty() { error_text } else { errors.format() };
+        
+        Err(AppError::jira_api_error(format!("이슈 수정 실패 ({}): {} - {}", issue_key, status, message)))
+    }
+    
+    pub async fn get_create_issue_types(&self, project_key: &str) -> AppResult<Vec<IssueType>> {
+        let url = format!("{}/issue/createmeta/{}/issuetypes", self.api_url(), project_key);
+        
+        let response = self
+            .client
+            .get(&url)
+            .query(&[("maxResults", "200")])
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        let meta: CreateMetaIssueTypes = self.handle_response(response, "이슈 타입 조회").await?;
+        Ok(meta.issue_types)
+    }
+    
+    pub async fn get_priorities(&self) -> AppResult<Vec<Priority>> {
+        let url = format!("{}/priority", self.api_url());
+        
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "우선순위 조회").await
+    }
+    
+    pub async fn create_issue(&self, project_key: &str, issue: &NewIssue) -> AppResult<CreatedIssue> {
+        let url = format!("{}/issue", self.api_url());
+        
+        let response = self
+            .client
+            .post(&url)
+            .json(&self.request_body(&issue.to_request(project_key)))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "이슈 생성").await
+    }
+    
     pub async fn get_issue(&self, issue_key: &str) -> AppResult<Issue> {
-        let url = format!("{}/rest/api/3/issue/{}", self.base_url, issue_key);
+        let url = format!("{}/issue/{}", self.api_url(), issue_key);
         
         let response = self
             .client
@@ -105,24 +267,35 @@ impl JiraClient {
         self.handle_response(response, &format!("이슈 조회: {}", issue_key)).await
     }
     
-    pub async fn search_assigned_issues(&self, assignee_email: &str, max_results: Option<i32>) -> AppResult<SearchResults> {
-        let jql = format!("project = {} AND assignee = \"{}\" ORDER BY priority DESC, updated DESC", 
-                         self.project_key, assignee_email);
+    pub async fn search_page(
+        &self,
+        jql: &Jql,
+        extra_fields: &[String],
+        page_size: usize,
+        cursor: &PageCursor,
+    ) -> AppResult<SearchPage> {
+        // Server에는 /search/jql이 없어 기존 /search를 startAt 페이지네이션으로 사용합니다
+        let url = match self.flavor {
+            JiraFlavor::Cloud => format!("{}/search/jql", self.api_url()),
+            JiraFlavor::Server => format!("{}/search", self.api_url()),
+        };
         
-        let url = format!("{}/rest/api/3/search", self.base_url);
-        let max_results = max_results.unwrap_or(50);
+        let mut fields: Vec<&str> = SEARCH_FIELDS.to_vec();
+        fields.extend(extra_fields.iter().map(|field| field.as_str()));
         
-        let body = json!({
-            "jql": jql,
-            "maxResults": max_results,
-            "startAt": 0,
-            "fields": [
-                "summary", "description", "status", "priority", 
-                "assignee", "reporter", "created", "updated",
-                "issuetype", "project"
-            ]
+        let mut body = json!({
+            "jql": jql.to_string(),
+            "maxResults": page_size,
+            "fields": fields
         });
         
+        match cursor {
+            PageCursor::Token(token) => body["nextPageToken"] = json!(token),
+            PageCursor::Offset(start_at) => body["startAt"] = json!(start_at),
+            PageCursor::First if !self.flavor.is_cloud() => body["startAt"] = json!(0),
+            PageCursor::First => {}
+        }
+        
         let response = self
             .client
             .post(&url)
@@ -131,26 +304,202 @@ impl JiraClient {
             .await
             .with_app_type(AppErrorType::JiraConnectionError)?;
         
-        self.handle_response(response, "할당된 이슈 검색").await
+        self.handle_response(response, &format!("이슈 검색: {}", jql)).await
+    }
+    
+    pub fn search_stream<'a>(
+        &'a self,
+        jql: &'a Jql,
+        extra_fields: &'a [String],
+        page_size: usize,
+    ) -> impl Stream<Item = AppResult<Issue>> + 'a {
+        // 상태: 다음에 요청할 페이지 위치 (None이면 검색 종료)
+        stream::try_unfold(Some(PageCursor::First), move |state| async move {
+            let Some(cursor) = state else {
+                return Ok::<_, AppError>(None);
+            };
+            
+            let page = self.search_page(jql, extra_fields, page_size, &cursor).await?;
+            let next_state = page.next_cursor();
+            let issues = stream::iter(page.issues.into_iter().map(Ok));
+            
+            Ok(Some((issues, next_state)))
+        })
+        .try_flatten()
     }
     
-    pub async fn search_issues_by_status(&self, status: &str, max_results: Option<i32>) -> AppResult<SearchResults> {
-        let jql = format!("project = {} AND status = \"{}\" ORDER BY updated DESC", 
-                         self.project_key, status);
+    pub async fn search_all(&self, jql: &Jql, limit: Option<usize>) -> AppResult<SearchResults> {
+        self.search_all_with_fields(jql, &[], limit).await
+    }
+    
+    pub async fn search_all_with_fields(
+        &self,
+        jql: &Jql,
+        extra_fields: &[String],
+        limit: Option<usize>,
+    ) -> AppResult<SearchResults> {
+        let page_size = limit.unwrap_or(SEARCH_PAGE_SIZE).clamp(1, SEARCH_PAGE_SIZE);
+        let mut stream = Box::pin(self.search_stream(jql, extra_fields, page_size));
+        let mut issues = Vec::new();
         
-        let url = format!("{}/rest/api/3/search", self.base_url);
-        let max_results = max_results.unwrap_or(50);
+        while let Some(issue) = stream.try_next().await? {
+            if limit.is_some_and(|limit| issues.len() >= limit) {
+                return Ok(SearchResults { issues, is_last: false });
+            }
+            issues.push(issue);
+        }
         
-        let body = json!({
-            "jql": jql,
-            "maxResults": max_results,
-            "startAt": 0,
-            "fields": [
-                "summary", "description", "status", "priority", 
-                "assignee", "reporter", "created", "updated",
-                "issuetype", "project"
-            ]
-        });
+        Ok(SearchResults { issues, is_last: true })
+    }
+    
+    // 존재하지 않는 키가 섞여 JQL이 실패하면 해당 묶음은 한 건씩 조회합니다
+    pub async fn get_issues(&self, issue_keys: &[String]) -> AppResult<Vec<Issue>> {
+        let mut issues = Vec::new();
+        
+        for chunk in issue_keys.chunks(SEARCH_PAGE_SIZE) {
+            let jql = Jql::with(Clause::in_list("key", chunk));
+            
+            match self.search_all(&jql, None).await {
+                Ok(results) => issues.extend(results.issues),
+                // JQL에 존재하지 않는 키가 있으면 400이 반환되므로 이 경우에만 한 건씩 조회하고
+                // 찾을 수 없는 키(404)만 건너뜁니다
+                Err(e) if matches!(e.error_type, AppErrorType::JiraBadRequest(_)) => {
+                    for key in chunk {
+                        match self.get_issue(key).await {
+                            Ok(issue) => issues.push(issue),
+                            Err(e) if matches!(e.error_type, AppErrorType::JiraTicketNotFound(_)) => {}
+                            Err(e) => return Err(e),
+                        }
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+        }
+        
+        issues.sort_by_key(|issue| issue_keys.iter().position(|key| key == &issue.key));
+        Ok(issues)
+    }
+    
+    pub async fn search_assigned_issues(&self, assignee_email: &str, limit: Option<usize>) -> AppResult<SearchResults> {
+        // 개인 액세스 토큰만 사용하는 Server 설정에는 사용자 이름이 없을 수 있습니다
+        let assignee = if assignee_email.is_empty() {
+            JqlValue::function("currentUser")
+        } else {
+            JqlValue::from(assignee_email)
+        };
+        
+        let jql = Jql::with(Clause::eq("project", &self.project_key))
+            .and(Clause::eq("assignee", assignee))
+            .order_by("priority", SortOrder::Desc)
+            .order_by("updated", SortOrder::Desc);
+        
+        self.search_all(&jql, limit).await
+    }
+    
+    pub async fn search_issues_by_status(&self, status: &str, limit: Option<usize>) -> AppResult<SearchResults> {
+        let jql = Jql::with(Clause::eq("project", &self.project_key))
+            .and(Clause::eq("status", status))
+            .order_by("updated", SortOrder::Desc);
+        
+        self.search_all(&jql, limit).await
+    }
+    
+    pub async fn search_unresolved_in_version(&self, version_name: &str) -> AppResult<SearchResults> {
+        let jql = Jql::with(Clause::eq("project", &self.project_key))
+            .and(Clause::eq("fixVersion", version_name))
+            .and(Clause::not_eq("statusCategory", "Done"))
+            .order_by("key", SortOrder::Asc);
+        
+        self.search_all(&jql, None).await
+    }
+    
+    pub async fn get_favourite_filters(&self) -> AppResult<Vec<Filter>> {
+        let url = format!("{}/filter/favourite", self.api_url());
+        
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "즐겨찾기 필터 조회").await
+    }
+    
+    pub async fn add_worklog(
+        &self,
+        issue_key: &str,
+        seconds: u64,
+        started: DateTime<Utc>,
+        comment: Option<&str>,
+    ) -> AppResult<Worklog> {
+        let url = format!("{}/issue/{}/worklog", self.api_url(), issue_key);
+        let request = WorklogRequest {
+            time_spent_seconds: seconds,
+            started: started.format("%Y-%m-%dT%H:%M:%S%.3f%z").to_string(),
+            comment: comment.filter(|comment| !comment.trim().is_empty()).map(adf::from_markdown),
+        };
+        
+        let response = self
+            .client
+            .post(&url)
+            .json(&self.request_body(&request))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, &format!("작업 시간 기록: {}", issue_key)).await
+    }
+    
+    pub async fn get_project(&self) -> AppResult<Project> {
+        let url = format!("{}/project/{}", self.api_url(), self.project_key);
+        
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "프로젝트 조회").await
+    }
+    
+    pub async fn get_project_versions(&self) -> AppResult<Vec<Version>> {
+        let url = format!("{}/project/{}/versions", self.api_url(), self.project_key);
+        
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "버전 목록 조회").await
+    }
+    
+    pub async fn find_version(&self, name: &str) -> AppResult<Option<Version>> {
+        let versions = self.get_project_versions().await?;
+        Ok(versions.into_iter().find(|version| version.name.eq_ignore_ascii_case(name)))
+    }
+    
+    pub async fn create_version(
+        &self,
+        name: &str,
+        description: Option<&str>,
+        release_date: Option<&str>,
+    ) -> AppResult<Version> {
+        let project = self.get_project().await?;
+        let project_id: u64 = project.id.parse()
+            .with_app_type(AppErrorType::JiraApiError(format!("프로젝트 {}의 id를 해석할 수 없습니다: {}", self.project_key, project.id)))?;
+        let url = format!("{}/version", self.api_url());
+        
+        let mut body = json!({ "name": name, "projectId": project_id });
+        if let Some(description) = description {
+            body["description"] = json!(description);
+        }
+        if let Some(release_date) = release_date {
+            body["releaseDate"] = json!(release_date);
+        }
         
         let response = self
             .client
@@ -160,11 +509,202 @@ impl JiraClient {
             .await
             .with_app_type(AppErrorType::JiraConnectionError)?;
         
-        self.handle_response(response, &format!("상태별 이슈 검색: {}", status)).await
+        self.handle_response(response, &format!("버전 생성: {}", name)).await
+    }
+    
+    pub async fn release_version(&self, version_id: &str, release_date: &str) -> AppResult<Version> {
+        let url = format!("{}/version/{}", self.api_url(), version_id);
+        
+        let response = self
+            .client
+            .put(&url)
+            .json(&json!({ "released": true, "releaseDate": release_date }))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, "버전 릴리스").await
+    }
+    
+    pub async fn get_comments(&self, issue_key: &str) -> AppResult<Vec<Comment>> {
+        let mut comments = Vec::new();
+        
+        loop {
+            let url = format!("{}/issue/{}/comment?startAt={}", self.api_url(), issue_key, comments.len());
+            
+            let response = self
+                .client
+                .get(&url)
+                .send()
+                .await
+                .with_app_type(AppErrorType::JiraConnectionError)?;
+            
+            let page: CommentPage = self
+                .handle_response(response, &format!("코멘트 조회: {}", issue_key))
+                .await?;
+            let is_empty = page.comments.is_empty();
+            comments.extend(page.comments);
+            
+            if is_empty || comments.len() >= page.total {
+                return Ok(comments);
+            }
+        }
+    }
+    
+    pub async fn add_comment(&self, issue_key: &str, body: Value) -> AppResult<Comment> {
+        let url = format!("{}/issue/{}/comment", self.api_url(), issue_key);
+        
+        let response = self
+            .client
+            .post(&url)
+            .json(&self.request_body(&json!({ "body": body })))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, &format!("코멘트 작성: {}", issue_key)).await
+    }
+    
+    pub async fn update_comment(&self, issue_key: &str, comment_id: &str, body: Value) -> AppResult<Comment> {
+        let url = format!("{}/issue/{}/comment/{}", self.api_url(), issue_key, comment_id);
+        
+        let response = self
+            .client
+            .put(&url)
+            .json(&self.request_body(&json!({ "body": body })))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, &format!("코멘트 수정: {}", issue_key)).await
+    }
+    
+    // 내가 작성한 코멘트 중 link_url로 연결된 코멘트가 있으면 새로 만들지 않고 수정합니다
+    pub async fn upsert_comment(&self, issue_key: &str, link_url: &str, body: Value) -> AppResult<Comment> {
+        let myself = self.get_myself().await?;
+        let comments = self.get_comments(issue_key).await?;
+        
+        match comments.iter().find(|comment| comment.is_authored_by(&myself) && comment.has_link(link_url)) {
+            Some(existing) => self.update_comment(issue_key, &existing.id, body).await,
+            None => self.add_comment(issue_key, body).await,
+        }
+    }
+    
+    // 같은 globalId의 링크가 있으면 Jira가 새로 만들지 않고 갱신합니다
+    pub async fn upsert_remote_link(&self, issue_key: &str, link: &RemoteLinkRequest) -> AppResult<RemoteLink> {
+        let url = format!("{}/issue/{}/remotelink", self.api_url(), issue_key);
+        
+        let response = self
+            .client
+            .post(&url)
+            .json(link)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        self.handle_response(response, &format!("원격 링크 등록: {}", issue_key)).await
+    }
+    
+    async fn get_agile_pages<T: for<'de> serde::Deserialize<'de>>(&self, path: &str, context: &str) -> AppResult<Vec<T>> {
+        let separator = if path.contains('?') { '&' } else { '?' };
+        let mut values = Vec::new();
+        
+        loop {
+            let url = format!("{}/rest/agile/1.0/{}{}startAt={}", self.base_url, path, separator, values.len());
+            
+            let response = self
+                .client
+                .get(&url)
+                .send()
+                .await
+                .with_app_type(AppErrorType::JiraConnectionError)?;
+            
+            let page: AgilePage<T> = self.handle_response(response, context).await?;
+            let is_empty = page.values.is_empty();
+            values.extend(page.values);
+            
+            if page.is_last || is_empty {
+                return Ok(values);
+            }
+        }
+    }
+    
+    pub async fn get_project_boards(&self) -> AppResult<Vec<Board>> {
+        let path = format!("board?projectKeyOrId={}", self.project_key);
+        self.get_agile_pages(&path, "보드 목록 조회").await
+    }
+    
+    pub async fn get_board_sprints(&self, board_id: u64, states: &str) -> AppResult<Vec<Sprint>> {
+        let path = format!("board/{}/sprint?state={}", board_id, states);
+        self.get_agile_pages(&path, &format!("스프린트 목록 조회: 보드 {}", board_id)).await
+    }
+    
+    pub async fn find_sprint(&self, board_ids: &[u64], selector: &SprintSelector) -> AppResult<Option<Sprint>> {
+        let states = match selector {
+            SprintSelector::Current => "active",
+            SprintSelector::Next => "future",
+            SprintSelector::Named(_) => "active,future,closed",
+        };
+        
+        let mut sprints = Vec::new();
+        for board_id in board_ids {
+            sprints.extend(self.get_board_sprints(*board_id, states).await?);
+        }
+        
+        let sprint = match selector {
+            SprintSelector::Current => sprints.into_iter().next(),
+            SprintSelector::Next => sprints
+                .into_iter()
+                .min_by_key(|sprint| (sprint.start_date.is_none(), sprint.start_date, sprint.id)),
+            SprintSelector::Named(name) => sprints
+                .into_iter()
+                .find(|sprint| sprint.name.to_lowercase() == name.to_lowercase()),
+        };
+        
+        Ok(sprint)
+    }
+    
+    pub async fn get_issue_sprint(&self, issue_key: &str) -> AppResult<Option<Sprint>> {
+        let url = format!("{}/rest/agile/1.0/issue/{}?fields=sprint", self.base_url, issue_key);
+        
+        let response = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        let issue: AgileIssue = self
+            .handle_response(response, &format!("이슈 스프린트 조회: {}", issue_key))
+            .await?;
+        
+        Ok(issue.fields.sprint)
+    }
+    
+    pub async fn move_issues_to_sprint(&self, sprint_id: u64, issue_keys: &[String]) -> AppResult<()> {
+        let url = format!("{}/rest/agile/1.0/sprint/{}/issue", self.base_url, sprint_id);
+        
+        let response = self
+            .client
+            .post(&url)
+            .json(&json!({ "issues": issue_keys }))
+            .send()
+            .await
+            .with_app_type(AppErrorType::JiraConnectionError)?;
+        
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            let error_text = response
+                .text()
+                .await
+                .unwrap_or_else(|_| "응답 읽기 실패".to_string());
+            Err(AppError::jira_api_error(format!("스프린트 이동 실패 ({}): {}", sprint_id, error_text)))
+        }
     }
     
     pub async fn get_transitions(&self, issue_key: &str) -> AppResult<Vec<Transition>> {
-        let url = format!("{}/rest/api/3/issue/{}/transitions", self.base_url, issue_key);
+        let url = format!("{}/issue/{}/transitions?expand=transitions.fields", self.api_url(), issue_key);
         
         let response = self
             .client
@@ -181,18 +721,29 @@ impl JiraClient {
     }
     
     pub async fn transition_issue(&self, issue_key: &str, transition_id: &str) -> AppResult<()> {
-        let url = format!("{}/rest/api/3/issue/{}/transitions", self.base_url, issue_key);
+        self.transition_issue_with(issue_key, transition_id, &TransitionInput::default()).await
+    }
+    
+    pub async fn transition_issue_with(
+        &self,
+        issue_key: &str,
+        transition_id: &str,
+        input: &TransitionInput,
+    ) -> AppResult<()> {
+        let url = format!("{}/issue/{}/transitions", self.api_url(), issue_key);
         
         let body = TransitionRequest {
             transition: TransitionId {
                 id: transition_id.to_string(),
             },
+            fields: input.fields.clone(),
+            update: input.update.clone(),
         };
         
         let response = self
             .client
             .post(&url)
-            .json(&body)
+            .json(&self.request_body(&body))
             .send()
             .await
             .with_app_type(AppErrorType::JiraConnectionError)?;
@@ -200,7 +751,13 @@ impl JiraClient {
         if response.status().is_success() {
             Ok(())
         } else if response.status() == reqwest::StatusCode::BAD_REQUEST {
-            Err(AppError::new(AppErrorType::JiraTransitionNotAllowed))
+            let errors: JiraErrorResponse = response.json().await.unwrap_or_default();
+            
+            if errors.is_empty() {
+                Err(AppError::new(AppErrorType::JiraTransitionNotAllowed))
+            } else {
+                Err(AppError::jira_api_error(format!("상태 전환 실패 ({}): {}", issue_key, errors.format())))
+            }
         } else {
             let error_text = response
                 .text()
@@ -223,37 +780,228 @@ impl JiraClient {
         Ok(None)
     }
     
-    pub async fn transition_to_status(&self, issue_key: &str, target_status: &str) -> AppResult<()> {
-        let transitions = self.get_transitions(issue_key).await?;
+    fn transition_not_found(issue_key: &str, target: &str, transitions: &[Transition], unexplored: &[String]) -> AppError {
+        let available = if transitions.is_empty() {
+            "전환 가능한 상태 없음".to_string()
+        } else {
+            let statuses: Vec<String> = transitions
+                .iter()
+                .map(|transition| format!("{} ({})", transition.name, transition.to.name))
+                .collect();
+            format!("가능한 상태: {}", statuses.join(", "))
+        };
         
-        // 가능한 전환 상태 찾기
-        let mut found_transition: Option<String> = None;
-        let mut available_statuses: Vec<String> = Vec::new();
+        // 중간 상태의 전환 목록은 그 상태에 있는 다른 이슈로만 알 수 있어, 없으면 경로를 더 찾지 못합니다
+        let unexplored = if unexplored.is_empty() {
+            String::new()
+        } else {
+            format!(
+                "\n같은 유형의 이슈가 없어 전환 목록을 확인하지 못한 중간 상태: {} (해당 상태를 거쳐 수동으로 전환해주세요)",
+                unexplored.join(", ")
+            )
+        };
+        
+        AppError::jira_api_error(format!(
+            "이슈 {}에서 '{}' 상태로 전환할 수 없습니다. {}{}", 
+            issue_key, target, available, unexplored
+        ))
+    }
+    
+    fn id_value(id: &str) -> JqlValue {
+        match id.parse::<i64>() {
+            Ok(id) => JqlValue::Number(id),
+            Err(_) => JqlValue::from(id),
+        }
+    }
+    
+    // 현재 상태가 아닌 상태에서의 전환 목록은 같은 상태에 있는 다른 이슈로부터 얻습니다
+    // 이슈 유형마다 워크플로우가 다를 수 있으므로 같은 유형의 이슈만 참고합니다
+    async fn transitions_from_status(&self, issue_key: &str, issue_type_id: &str, status_id: &str) -> AppResult<Option<Vec<Transition>>> {
+        let jql = Jql::with(Clause::eq("project", &self.project_key))
+            .and(Clause::eq("issuetype", Self::id_value(issue_type_id)))
+            .and(Clause::eq("status", Self::id_value(status_id)))
+            .and(Clause::not_eq("key", issue_key))
+            .order_by("updated", SortOrder::Desc);
+        
+        let results = self.search_page(&jql, &[], 1, &PageCursor::First).await?;
+        
+        match results.issues.first() {
+            Some(sample) => Ok(Some(self.get_transitions(&sample.key).await?)),
+            None => Ok(None),
+        }
+    }
+    
+    pub async fn find_transition_path(
+        &self,
+        issue_key: &str,
+        workflow: &WorkflowConfig,
+        target: &TransitionTarget,
+    ) -> AppResult<TransitionPath> {
+        let issue = self.get_issue(issue_key).await?;
+        let issue_type_id = issue.fields.issuetype.id;
+        let from = issue.fields.status;
+        let current_transitions = self.get_transitions(issue_key).await?;
+        
+        let mut visited: HashSet<String> = HashSet::from([from.id.clone()]);
+        let mut unexplored: Vec<String> = Vec::new();
+        let mut queue: VecDeque<(String, Vec<Transition>)> = VecDeque::from([(from.id.clone(), Vec::new())]);
         
-        for transition in &transitions {
-            available_statuses.push(format!("{} ({})", transition.name, transition.to.name));
+        let max_steps = if target.is_direct() { 1 } else { MAX_TRANSITION_STEPS };
+        
+        while let Some((status_id, steps)) = queue.pop_front() {
+            if steps.len() >= max_steps {
+                continue;
+            }
+            
+            let transitions = if steps.is_empty() {
+                current_transitions.clone()
+            } else {
+                match self.transitions_from_status(issue_key, &issue_type_id, &status_id).await? {
+                    Some(transitions) => transitions,
+                    None => {
+                        unexplored.extend(steps.last().map(|step| step.to.name.clone()));
+                        continue;
+                    }
+                }
+            };
+            
+            if let Some(transition) = transitions.iter().find(|t| target.matches(workflow, t)) {
+                let mut steps = steps;
+                steps.push(transition.clone());
+                return Ok(TransitionPath { from, steps });
+            }
             
-            if transition.name.to_lowercase() == target_status.to_lowercase() ||
-               transition.to.name.to_lowercase() == target_status.to_lowercase() ||
-               (target_status == "In Review" && (transition.to.name == "리뷰 중" || transition.to.name == "검토 중" || transition.to.name == "Review")) {
-                found_transition = Some(transition.id.clone());
-                break;
+            for transition in transitions {
+                if visited.insert(transition.to.id.clone()) {
+                    let mut next_steps = steps.clone();
+                    next_steps.push(transition.clone());
+                    queue.push_back((transition.to.id.clone(), next_steps));
+                }
             }
         }
         
-        if let Some(transition_id) = found_transition {
-            self.transition_issue(issue_key, &transition_id).await
-        } else {
-            let available = if available_statuses.is_empty() {
-                "전환 가능한 상태 없음".to_string()
-            } else {
-                format!("가능한 상태: {}", available_statuses.join(", "))
+        Err(Self::transition_not_found(issue_key, &target.label(workflow), &current_transitions, &unexplored))
+    }
+    
+    // inputs는 path.steps와 같은 순서로 각 전환 화면의 필드 값을 담습니다
+    pub async fn apply_transition_path(
+        &self,
+        issue_key: &str,
+        path: &TransitionPath,
+        inputs: &[TransitionInput],
+    ) -> AppResult<()> {
+        let total = path.steps.len();
+        let empty_input = TransitionInput::default();
+        
+        for (index, step) in path.steps.iter().enumerate() {
+            let input = inputs.get(index).unwrap_or(&empty_input);
+            let step_failed = |reason: String| AppErrorType::JiraTransitionStepFailed {
+                step: index + 1,
+                total,
+                transition: format!("{} → {}", step.name, step.to.name),
+                reason,
             };
             
-            Err(AppError::jira_api_error(format!(
-                "이슈 {}에서 '{}' 상태로 전환할 수 없습니다. {}", 
-                issue_key, target_status, available
-            )))
+            let transitions = self.get_transitions(issue_key).await
+                .map_err(|e| step_failed(e.to_string()))?;
+            
+            // 경로의 전환은 다른 이슈에서 얻은 것일 수 있으므로 실제 이슈의 전환 중
+            // 목표 상태가 같은 것을 사용합니다 (같은 id가 있으면 우선)
+            let candidates: Vec<&Transition> = transitions
+                .iter()
+                .filter(|t| t.to.id == step.to.id)
+                .collect();
+            let transition = candidates
+                .iter()
+                .find(|t| t.id == step.id)
+                .or_else(|| candidates.first())
+                .ok_or_else(|| step_failed("현재 상태에서 사용할 수 없는 전환입니다".to_string()))?;
+            
+            self.transition_issue_with(issue_key, &transition.id, input).await
+                .map_err(|e| step_failed(e.to_string()))?;
         }
+        
+        Ok(())
     }
-}
\ No newline at end of file
+    
+    pub async fn transition_to_status(&self, issue_key: &str, target_status: &str) -> AppResult<()> {
+        let target = TransitionTarget::Status(target_status.to_string());
+        let workflow = WorkflowConfig::default();
+        let path = self.find_transition_path(issue_key, &workflow, &target).await?;
+        let inputs = path.default_inputs(&workflow);
+        self.apply_transition_path(issue_key, &path, &inputs).await
+    }
+    
+    pub async fn transition_to_stage(
+        &self,
+        issue_key: &str,
+        workflow: &WorkflowConfig,
+        stage: WorkflowStage,
+    ) -> AppResult<()> {
+        let path = self.find_transition_path(issue_key, workflow, &TransitionTarget::Stage(stage)).await?;
+        let inputs = path.default_inputs(workflow);
+        self.apply_transition_path(issue_key, &path, &inputs).await
+    }
+}
+
+#[async_trait]
+impl IssueTracker for JiraClient {
+    fn name(&self) -> &'static str {
+        "Jira"
+    }
+    
+    async fn get_issue(&self, issue_key: &str) -> AppResult<Issue> {
+        JiraClient::get_issue(self, issue_key).await
+    }
+    
+    async fn get_issues(&self, issue_keys: &[String]) -> AppResult<Vec<Issue>> {
+        JiraClient::get_issues(self, issue_keys).await
+    }
+    
+    async fn search_assigned_issues(&self, limit: Option<usize>) -> AppResult<SearchResults> {
+        JiraClient::search_assigned_issues(self, &self.username, limit).await
+    }
+    
+    async fn search_issues_by_status(&self, status: &str, limit: Option<usize>) -> AppResult<SearchResults> {
+        JiraClient::search_issues_by_status(self, status, limit).await
+    }
+    
+    async fn get_transitions(&self, issue_key: &str) -> AppResult<Vec<Transition>> {
+        JiraClient::get_transitions(self, issue_key).await
+    }
+    
+    async fn find_transition_path(
+        &self,
+        issue_key: &str,
+        workflow: &WorkflowConfig,
+        target: &TransitionTarget,
+    ) -> AppResult<TransitionPath> {
+        JiraClient::find_transition_path(self, issue_key, workflow, target).await
+    }
+    
+    async fn apply_transition_path(
+        &self,
+        issue_key: &str,
+        path: &TransitionPath,
+        inputs: &[TransitionInput],
+    ) -> AppResult<()> {
+        JiraClient::apply_transition_path(self, issue_key, path, inputs).await
+    }
+    
+    async fn get_comments(&self, issue_key: &str) -> AppResult<Vec<Comment>> {
+        JiraClient::get_comments(self, issue_key).await
+    }
+    
+    async fn upsert_comment(&self, issue_key: &str, link_url: &str, body: &str) -> AppResult<()> {
+        JiraClient::upsert_comment(self, issue_key, link_url, adf::from_markdown(body)).await?;
+        Ok(())
+    }
+    
+    async fn get_myself(&self) -> AppResult<User> {
+        JiraClient::get_myself(self).await
+    }
+    
+    async fn update_issue(&self, issue_key: &str, update: &IssueUpdate) -> AppResult<()> {
+        JiraClient::update_issue(self, issue_key, update).await
+    }
+}
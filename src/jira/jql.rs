@@ -0,0 +1,486 @@
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JqlValue {
+    String(String),
+    Number(i64),
+    Function(String, Vec<String>),
+    List(Vec<JqlValue>),
+    Empty,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operator {
+    Eq,
+    NotEq,
+    In,
+    NotIn,
+    Is,
+    IsNot,
+    Contains,
+    Greater,
+    GreaterOrEq,
+    Less,
+    LessOrEq,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Clause {
+    Compare {
+        field: String,
+        operator: Operator,
+        value: JqlValue,
+    },
+    And(Vec<Clause>),
+    Or(Vec<Clause>),
+    Not(Box<Clause>),
+    // 사용자가 직접 입력한 JQL 조건 (이스케이프하지 않음)
+    Raw(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum OrderBy {
+    Field(String, SortOrder),
+    Raw(String),
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Jql {
+    clause: Option<Clause>,
+    order_by: Vec<OrderBy>,
+}
+
+pub fn escape_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+// 영문/숫자/_/. 로만 된 필드명과 cf[10020] 형식은 그대로, 그 외("Story Points" 등)는 따옴표로 감쌉니다
+fn format_field(field: &str) -> String {
+    let is_simple = !field.is_empty()
+        && field.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '.');
+    let is_custom_field_ref = field.starts_with("cf[")
+        && field.ends_with(']')
+        && field[3..field.len() - 1].chars().all(|c| c.is_ascii_digit());
+
+    if is_simple || is_custom_field_ref {
+        field.to_string()
+    } else {
+        escape_string(field)
+    }
+}
+
+fn is_function_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl fmt::Display for JqlValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            JqlValue::String(value) => write!(f, "{}", escape_string(value)),
+            JqlValue::Number(value) => write!(f, "{}", value),
+            JqlValue::Function(name, args) => {
+                let args: Vec<String> = args.iter().map(|arg| escape_string(arg)).collect();
+                write!(f, "{}({})", name, args.join(", "))
+            }
+            JqlValue::List(values) => {
+                let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+                write!(f, "({})", values.join(", "))
+            }
+            JqlValue::Empty => write!(f, "EMPTY"),
+        }
+    }
+}
+
+impl From<&str> for JqlValue {
+    fn from(value: &str) -> Self {
+        JqlValue::String(value.to_string())
+    }
+}
+
+impl From<String> for JqlValue {
+    fn from(value: String) -> Self {
+        JqlValue::String(value)
+    }
+}
+
+impl From<&String> for JqlValue {
+    fn from(value: &String) -> Self {
+        JqlValue::String(value.clone())
+    }
+}
+
+impl From<i64> for JqlValue {
+    fn from(value: i64) -> Self {
+        JqlValue::Number(value)
+    }
+}
+
+impl JqlValue {
+    pub fn function(name: &str) -> Self {
+        debug_assert!(is_function_name(name), "잘못된 JQL 함수명: {}", name);
+        JqlValue::Function(name.to_string(), Vec::new())
+    }
+
+    pub fn function_with_args(name: &str, args: &[&str]) -> Self {
+        debug_assert!(is_function_name(name), "잘못된 JQL 함수명: {}", name);
+        JqlValue::Function(name.to_string(), args.iter().map(|arg| arg.to_string()).collect())
+    }
+
+    pub fn list<T: Into<JqlValue>>(values: impl IntoIterator<Item = T>) -> Self {
+        JqlValue::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = match self {
+            Operator::Eq => "=",
+            Operator::NotEq => "!=",
+            Operator::In => "IN",
+            Operator::NotIn => "NOT IN",
+            Operator::Is => "IS",
+            Operator::IsNot => "IS NOT",
+            Operator::Contains => "~",
+            Operator::Greater => ">",
+            Operator::GreaterOrEq => ">=",
+            Operator::Less => "<",
+            Operator::LessOrEq => "<=",
+        };
+        write!(f, "{}", operator)
+    }
+}
+
+impl Clause {
+    pub fn compare(field: &str, operator: Operator, value: impl Into<JqlValue>) -> Self {
+        Clause::Compare {
+            field: field.to_string(),
+            operator,
+            value: value.into(),
+        }
+    }
+
+    pub fn eq(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::compare(field, Operator::Eq, value)
+    }
+
+    pub fn not_eq(field: &str, value: impl Into<JqlValue>) -> Self {
+        Self::compare(field, Operator::NotEq, value)
+    }
+
+    pub fn in_list<T: Into<JqlValue>>(field: &str, values: impl IntoIterator<Item = T>) -> Self {
+        Self::compare(field, Operator::In, JqlValue::list(values))
+    }
+
+    pub fn not_in_list<T: Into<JqlValue>>(field: &str, values: impl IntoIterator<Item = T>) -> Self {
+        Self::compare(field, Operator::NotIn, JqlValue::list(values))
+    }
+
+    pub fn is_empty(field: &str) -> Self {
+        Self::compare(field, Operator::Is, JqlValue::Empty)
+    }
+
+    pub fn is_not_empty(field: &str) -> Self {
+        Self::compare(field, Operator::IsNot, JqlValue::Empty)
+    }
+
+    pub fn contains(field: &str, text: &str) -> Self {
+        Self::compare(field, Operator::Contains, text)
+    }
+
+    pub fn raw(jql: &str) -> Self {
+        Clause::Raw(jql.trim().to_string())
+    }
+
+    pub fn negate(clause: Clause) -> Self {
+        Clause::Not(Box::new(clause))
+    }
+
+    pub fn and(self, other: Clause) -> Self {
+        match self {
+            Clause::And(mut clauses) => {
+                clauses.push(other);
+                Clause::And(clauses)
+            }
+            clause => Clause::And(vec![clause, other]),
+        }
+    }
+
+    pub fn or(self, other: Clause) -> Self {
+        match self {
+            Clause::Or(mut clauses) => {
+                clauses.push(other);
+                Clause::Or(clauses)
+            }
+            clause => Clause::Or(vec![clause, other]),
+        }
+    }
+
+    fn is_compound(&self) -> bool {
+        match self {
+            Clause::And(clauses) | Clause::Or(clauses) => clauses.len() > 1,
+            Clause::Raw(_) => true,
+            _ => false,
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_compound() {
+            write!(f, "({})", self)
+        } else {
+            write!(f, "{}", self)
+        }
+    }
+}
+
+impl fmt::Display for Clause {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Clause::Compare { field, operator, value } => {
+                write!(f, "{} {} {}", format_field(field), operator, value)
+            }
+            Clause::And(clauses) | Clause::Or(clauses) => {
+                let separator = if matches!(self, Clause::And(_)) { " AND " } else { " OR " };
+
+                for (index, clause) in clauses.iter().enumerate() {
+                    if index > 0 {
+                        write!(f, "{}", separator)?;
+                    }
+
+                    if clauses.len() > 1 {
+                        clause.fmt_nested(f)?;
+                    } else {
+                        write!(f, "{}", clause)?;
+                    }
+                }
+
+                Ok(())
+            }
+            Clause::Not(clause) => {
+                write!(f, "NOT ")?;
+                clause.fmt_nested(f)
+            }
+            Clause::Raw(jql) => write!(f, "{}", jql),
+        }
+    }
+}
+
+// 따옴표 밖에 있는 첫 번째 ORDER BY의 위치를 찾습니다
+fn find_order_by(jql: &str) -> Option<usize> {
+    let upper = jql.to_ascii_uppercase();
+    let bytes = upper.as_bytes();
+    let mut in_quote: Option<u8> = None;
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let c = bytes[index];
+
+        match in_quote {
+            Some(_) if c == b'\\' => index += 1,
+            Some(quote) if c == quote => in_quote = None,
+            Some(_) => {}
+            None if c == b'"' || c == b'\'' => in_quote = Some(c),
+            None if upper[index..].starts_with("ORDER BY")
+                && (index == 0 || bytes[index - 1].is_ascii_whitespace()) => return Some(index),
+            None => {}
+        }
+
+        index += 1;
+    }
+
+    None
+}
+
+impl Jql {
+    pub fn new() -> Self {
+        Jql::default()
+    }
+
+    pub fn with(clause: Clause) -> Self {
+        Jql {
+            clause: Some(clause),
+            order_by: Vec::new(),
+        }
+    }
+
+    // 사용자가 입력한 전체 JQL을 조건과 ORDER BY로 나눕니다
+    pub fn from_raw(jql: &str) -> Self {
+        let (condition, order_by) = match find_order_by(jql) {
+            Some(index) => (&jql[..index], Some(jql[index + "ORDER BY".len()..].trim())),
+            None => (jql, None),
+        };
+
+        let condition = condition.trim();
+
+        Jql {
+            clause: (!condition.is_empty()).then(|| Clause::raw(condition)),
+            order_by: order_by
+                .filter(|order_by| !order_by.is_empty())
+                .map(|order_by| vec![OrderBy::Raw(order_by.to_string())])
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn and(mut self, clause: Clause) -> Self {
+        self.clause = Some(match self.clause.take() {
+            Some(existing) => existing.and(clause),
+            None => clause,
+        });
+        self
+    }
+
+    pub fn or(mut self, clause: Clause) -> Self {
+        self.clause = Some(match self.clause.take() {
+            Some(existing) => existing.or(clause),
+            None => clause,
+        });
+        self
+    }
+
+    pub fn order_by(mut self, field: &str, order: SortOrder) -> Self {
+        self.order_by.push(OrderBy::Field(field.to_string(), order));
+        self
+    }
+
+    pub fn replace_order_by_raw(mut self, order_by: &str) -> Self {
+        self.order_by = vec![OrderBy::Raw(order_by.trim().to_string())];
+        self
+    }
+
+    pub fn clause(&self) -> Option<&Clause> {
+        self.clause.as_ref()
+    }
+}
+
+impl fmt::Display for Jql {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if let Some(ref clause) = self.clause {
+            write!(f, "{}", clause)?;
+        }
+
+        if !self.order_by.is_empty() {
+            if self.clause.is_some() {
+                write!(f, " ")?;
+            }
+
+            let terms: Vec<String> = self.order_by
+                .iter()
+                .map(|term| match term {
+                    OrderBy::Field(field, SortOrder::Asc) => format!("{} ASC", format_field(field)),
+                    OrderBy::Field(field, SortOrder::Desc) => format!("{} DESC", format_field(field)),
+                    OrderBy::Raw(raw) => raw.clone(),
+                })
+                .collect();
+
+            write!(f, "ORDER BY {}", terms.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_quotes_and_backslashes() {
+        assert_eq!(escape_string(r#"In "Review""#), r#""In \"Review\"""#);
+        assert_eq!(escape_string(r"C:\path"), r#""C:\\path""#);
+        assert_eq!(escape_string(r#"\""#), r#""\\\"""#);
+    }
+
+    #[test]
+    fn escapes_control_characters() {
+        assert_eq!(escape_string("a\nb\tc\rd"), r#""a\nb\tc\rd""#);
+        assert_eq!(escape_string("\u{0007}"), r#""\u0007""#);
+    }
+
+    #[test]
+    fn keeps_unicode_and_jql_reserved_words_inside_quotes() {
+        assert_eq!(escape_string("진행 중"), "\"진행 중\"");
+        assert_eq!(
+            Clause::eq("status", "Done\" OR project = OTHER").to_string(),
+            r#"status = "Done\" OR project = OTHER""#
+        );
+        assert_eq!(Clause::eq("summary", "AND").to_string(), r#"summary = "AND""#);
+    }
+
+    #[test]
+    fn quotes_field_names_with_spaces() {
+        assert_eq!(Clause::eq("Story Points", 3_i64).to_string(), r#""Story Points" = 3"#);
+        assert_eq!(Clause::eq("cf[10020]", 1_i64).to_string(), "cf[10020] = 1");
+        assert_eq!(Clause::eq("customfield_10020", 1_i64).to_string(), "customfield_10020 = 1");
+    }
+
+    #[test]
+    fn renders_functions_lists_and_empty() {
+        assert_eq!(
+            Clause::eq("assignee", JqlValue::function("currentUser")).to_string(),
+            "assignee = currentUser()"
+        );
+        assert_eq!(
+            Clause::in_list("key", ["EM-1", "EM-2"]).to_string(),
+            r#"key IN ("EM-1", "EM-2")"#
+        );
+        assert_eq!(Clause::is_empty("sprint").to_string(), "sprint IS EMPTY");
+    }
+
+    #[test]
+    fn combines_clauses_with_parentheses() {
+        let jql = Jql::with(Clause::eq("project", "EM"))
+            .and(Clause::eq("status", "To Do").or(Clause::eq("status", "Doing")))
+            .order_by("updated", SortOrder::Desc);
+
+        assert_eq!(
+            jql.to_string(),
+            r#"project = "EM" AND (status = "To Do" OR status = "Doing") ORDER BY updated DESC"#
+        );
+    }
+
+    #[test]
+    fn wraps_raw_and_negated_clauses() {
+        let jql = Jql::with(Clause::eq("project", "EM"))
+            .and(Clause::raw("labels = a OR labels = b"))
+            .and(Clause::negate(Clause::eq("status", "Done")));
+
+        assert_eq!(
+            jql.to_string(),
+            r#"project = "EM" AND (labels = a OR labels = b) AND NOT status = "Done""#
+        );
+    }
+
+    #[test]
+    fn splits_raw_jql_at_order_by() {
+        let jql = Jql::from_raw("project = EM order by created DESC");
+        assert_eq!(jql.clause(), Some(&Clause::raw("project = EM")));
+        assert_eq!(jql.to_string(), "project = EM ORDER BY created DESC");
+
+        let jql = Jql::from_raw(r#"summary ~ "sort ORDER BY name" ORDER BY rank"#);
+        assert_eq!(jql.clause(), Some(&Clause::raw(r#"summary ~ "sort ORDER BY name""#)));
+
+        let jql = Jql::from_raw("ORDER BY rank").replace_order_by_raw("priority DESC");
+        assert_eq!(jql.to_string(), "ORDER BY priority DESC");
+    }
+}
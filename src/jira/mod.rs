@@ -1,5 +1,13 @@
+pub mod adf;
 pub mod client;
+pub mod jql;
 pub mod models;
+pub mod ticket_key;
+pub mod workflow;
+pub mod worklog;
 
-pub use client::JiraClient;
-pub use models::*;
\ No newline at end of file
+pub use client::{JiraClient, JiraFlavor};
+pub use jql::{Clause, Jql, JqlValue, SortOrder};
+pub use models::*;
+pub use ticket_key::TicketKeyParser;
+pub use workflow::{TransitionPath, TransitionTarget, WorkflowConfig, WorkflowStage};
\ No newline at end of file
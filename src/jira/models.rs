@@ -1,5 +1,8 @@
+use super::adf;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+use std::str::FromStr;
 use chrono::{DateTime, Utc};
 use strum::{Display, EnumString};
 
@@ -22,6 +25,152 @@ pub struct IssueFields {
     pub updated: DateTime<Utc>,
     pub issuetype: IssueType,
     pub project: Project,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    #[serde(default)]
+    pub components: Vec<Component>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<IssueRef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subtasks: Vec<IssueRef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub issuelinks: Vec<IssueLink>,
+    #[serde(rename = "fixVersions", default, skip_serializing_if = "Vec::is_empty")]
+    pub fix_versions: Vec<Version>,
+    #[serde(rename = "attachment", default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<Attachment>,
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+// parent, subtasks 처럼 다른 이슈를 요약된 형태로 참조하는 필드
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRef {
+    pub id: Option<String>,
+    pub key: String,
+    pub fields: Option<IssueRefFields>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueRefFields {
+    pub summary: Option<String>,
+    pub status: Option<Status>,
+    pub issuetype: Option<IssueType>,
+}
+
+impl IssueRef {
+    pub fn summary(&self) -> &str {
+        self.fields
+            .as_ref()
+            .and_then(|fields| fields.summary.as_deref())
+            .unwrap_or_default()
+    }
+    
+    pub fn status(&self) -> Option<&Status> {
+        self.fields.as_ref().and_then(|fields| fields.status.as_ref())
+    }
+    
+    pub fn format_summary(&self) -> String {
+        format!("[{}] {}", self.key, self.summary())
+    }
+    
+    pub fn is_done(&self) -> bool {
+        self.status()
+            .and_then(|status| status.status_category.as_ref())
+            .is_some_and(|category| category.key == "done")
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLink {
+    pub id: Option<String>,
+    #[serde(rename = "type")]
+    pub link_type: IssueLinkType,
+    #[serde(rename = "inwardIssue", skip_serializing_if = "Option::is_none")]
+    pub inward_issue: Option<IssueRef>,
+    #[serde(rename = "outwardIssue", skip_serializing_if = "Option::is_none")]
+    pub outward_issue: Option<IssueRef>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct IssueLinkType {
+    pub name: String,
+    pub inward: String,
+    pub outward: String,
+}
+
+impl IssueLink {
+    // 현재 이슈 기준의 관계 설명(예: "is blocked by")과 연결된 이슈
+    pub fn relation(&self) -> Option<(&str, &IssueRef)> {
+        match (&self.outward_issue, &self.inward_issue) {
+            (Some(issue), _) => Some((self.link_type.outward.as_str(), issue)),
+            (None, Some(issue)) => Some((self.link_type.inward.as_str(), issue)),
+            (None, None) => None,
+        }
+    }
+    
+    // Jira 기본 "Blocks" 링크 또는 이름을 바꾼 차단 링크 타입
+    pub fn is_blocking_type(&self) -> bool {
+        self.link_type.name.eq_ignore_ascii_case("blocks")
+            || self.link_type.inward.to_lowercase().contains("blocked by")
+    }
+    
+    // 현재 이슈를 막고 있는 이슈 (inward 방향)
+    pub fn blocker(&self) -> Option<&IssueRef> {
+        self.inward_issue.as_ref().filter(|_| self.is_blocking_type())
+    }
+    
+    // 현재 이슈가 막고 있는 이슈 (outward 방향)
+    pub fn blocked(&self) -> Option<&IssueRef> {
+        self.outward_issue.as_ref().filter(|_| self.is_blocking_type())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Version {
+    pub id: Option<String>,
+    pub name: String,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub released: bool,
+    #[serde(default)]
+    pub archived: bool,
+    #[serde(rename = "releaseDate", skip_serializing_if = "Option::is_none")]
+    pub release_date: Option<String>,
+}
+
+impl Version {
+    pub fn format_state(&self) -> String {
+        match (&self.release_date, self.released) {
+            (Some(date), true) => format!("릴리스됨 {}", date),
+            (None, true) => "릴리스됨".to_string(),
+            (Some(date), false) => format!("미릴리스 (예정일 {})", date),
+            (None, false) => "미릴리스".to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Attachment {
+    pub id: String,
+    pub filename: String,
+    #[serde(default)]
+    pub size: u64,
+    #[serde(rename = "mimeType")]
+    pub mime_type: Option<String>,
+    pub content: Option<String>,
+    pub author: Option<User>,
+    pub created: Option<DateTime<Utc>>,
+}
+
+impl Attachment {
+    pub fn format_size(&self) -> String {
+        match self.size {
+            size if size >= 1024 * 1024 => format!("{:.1} MB", size as f64 / (1024.0 * 1024.0)),
+            size if size >= 1024 => format!("{:.1} KB", size as f64 / 1024.0),
+            size => format!("{} B", size),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -50,13 +199,33 @@ pub struct Priority {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct User {
+    #[serde(rename = "accountId")]
     pub account_id: Option<String>,
+    // Jira Server는 accountId 대신 사용자 이름(name)으로 식별합니다
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "emailAddress")]
     pub email_address: Option<String>,
     #[serde(rename = "displayName")]
     pub display_name: Option<String>,
     pub active: Option<bool>,
 }
 
+impl User {
+    // Cloud는 accountId, Server는 name으로 같은 사용자인지 비교합니다
+    pub fn is_same(&self, other: &User) -> bool {
+        match (&self.account_id, &self.name) {
+            (Some(account_id), _) => other.account_id.as_ref() == Some(account_id),
+            (None, Some(name)) => other.name.as_ref() == Some(name),
+            (None, None) => false,
+        }
+    }
+    
+    pub fn can_be_assigned(&self) -> bool {
+        self.account_id.is_some() || self.name.is_some()
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IssueType {
     pub id: String,
@@ -65,6 +234,33 @@ pub struct IssueType {
     pub subtask: bool,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreateMetaIssueTypes {
+    #[serde(rename = "issueTypes", alias = "values", default)]
+    pub issue_types: Vec<IssueType>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct NewIssue {
+    pub issue_type_id: String,
+    pub summary: String,
+    pub description: Option<String>,
+    pub priority: Option<String>,
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CreatedIssue {
+    pub id: String,
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Component {
+    pub id: Option<String>,
+    pub name: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub id: String,
@@ -73,14 +269,168 @@ pub struct Project {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SearchResults {
-    pub expand: Option<String>,
+pub struct SearchPage {
+    #[serde(default)]
+    pub issues: Vec<Issue>,
+    #[serde(rename = "nextPageToken")]
+    pub next_page_token: Option<String>,
+    #[serde(rename = "isLast")]
+    pub is_last: Option<bool>,
+    // Jira Server(/rest/api/2/search)는 토큰 대신 startAt/total로 페이지를 나눕니다
     #[serde(rename = "startAt")]
-    pub start_at: i32,
-    #[serde(rename = "maxResults")]
-    pub max_results: i32,
-    pub total: i32,
+    pub start_at: Option<usize>,
+    pub total: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum PageCursor {
+    #[default]
+    First,
+    Token(String),
+    Offset(usize),
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResults {
     pub issues: Vec<Issue>,
+    // limit 때문에 잘린 결과가 있으면 false
+    pub is_last: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Filter {
+    pub id: String,
+    pub name: String,
+    pub jql: Option<String>,
+    pub description: Option<String>,
+    #[serde(rename = "viewUrl")]
+    pub view_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorklogRequest {
+    #[serde(rename = "timeSpentSeconds")]
+    pub time_spent_seconds: u64,
+    // Jira 형식: 2024-01-01T10:00:00.000+0000
+    pub started: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Worklog {
+    pub id: String,
+    #[serde(rename = "timeSpent")]
+    pub time_spent: Option<String>,
+    #[serde(rename = "timeSpentSeconds", default)]
+    pub time_spent_seconds: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Comment {
+    pub id: String,
+    pub author: Option<User>,
+    pub body: Option<Value>,
+    pub created: Option<DateTime<Utc>>,
+    pub updated: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommentPage {
+    #[serde(rename = "startAt", default)]
+    pub start_at: usize,
+    #[serde(default)]
+    pub total: usize,
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteLinkRequest {
+    #[serde(rename = "globalId")]
+    pub global_id: String,
+    pub application: RemoteLinkApplication,
+    pub relationship: String,
+    pub object: RemoteLinkObject,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteLinkApplication {
+    #[serde(rename = "type")]
+    pub app_type: String,
+    pub name: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteLinkObject {
+    pub url: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<RemoteLinkIcon>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteLinkIcon {
+    #[serde(rename = "url16x16")]
+    pub url: String,
+    pub title: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RemoteLink {
+    pub id: u64,
+    #[serde(rename = "self")]
+    pub self_url: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Board {
+    pub id: u64,
+    pub name: String,
+    #[serde(rename = "type")]
+    pub board_type: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sprint {
+    pub id: u64,
+    pub name: String,
+    pub state: String,
+    #[serde(rename = "startDate")]
+    pub start_date: Option<DateTime<Utc>>,
+    #[serde(rename = "endDate")]
+    pub end_date: Option<DateTime<Utc>>,
+    pub goal: Option<String>,
+    #[serde(rename = "originBoardId")]
+    pub origin_board_id: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgilePage<T> {
+    #[serde(rename = "startAt", default)]
+    pub start_at: usize,
+    #[serde(rename = "isLast", default)]
+    pub is_last: bool,
+    pub values: Vec<T>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgileIssue {
+    pub key: String,
+    pub fields: AgileIssueFields,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgileIssueFields {
+    pub sprint: Option<Sprint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SprintSelector {
+    Current,
+    Next,
+    Named(String),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -88,6 +438,28 @@ pub struct Transition {
     pub id: String,
     pub name: String,
     pub to: Status,
+    #[serde(default)]
+    pub fields: HashMap<String, TransitionField>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TransitionField {
+    pub required: bool,
+    pub name: String,
+    pub schema: Option<FieldSchema>,
+    #[serde(rename = "hasDefaultValue", default)]
+    pub has_default_value: bool,
+    #[serde(rename = "allowedValues", default)]
+    pub allowed_values: Vec<Value>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FieldSchema {
+    #[serde(rename = "type")]
+    pub field_type: String,
+    pub items: Option<String>,
+    pub system: Option<String>,
+    pub custom: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -98,6 +470,32 @@ pub struct TransitionList {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransitionRequest {
     pub transition: TransitionId,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub fields: Map<String, Value>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub update: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct IssueUpdate {
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub fields: Map<String, Value>,
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub update: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TransitionInput {
+    pub fields: Map<String, Value>,
+    pub update: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct JiraErrorResponse {
+    #[serde(rename = "errorMessages", default)]
+    pub error_messages: Vec<String>,
+    #[serde(default)]
+    pub errors: HashMap<String, String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -105,7 +503,228 @@ pub struct TransitionId {
     pub id: String,
 }
 
+impl TransitionField {
+    pub fn allowed_value_label(value: &Value) -> String {
+        ["name", "value", "displayName", "id"]
+            .iter()
+            .find_map(|key| value.get(key).and_then(|v| v.as_str()))
+            .unwrap_or_default()
+            .to_string()
+    }
+}
+
+impl NewIssue {
+    pub fn to_request(&self, project_key: &str) -> Value {
+        let mut fields = Map::new();
+        fields.insert("project".to_string(), json!({ "key": project_key }));
+        fields.insert("issuetype".to_string(), json!({ "id": self.issue_type_id }));
+        fields.insert("summary".to_string(), json!(self.summary));
+        
+        if let Some(ref description) = self.description {
+            fields.insert("description".to_string(), adf::from_markdown(description));
+        }
+        if let Some(ref priority) = self.priority {
+            fields.insert("priority".to_string(), json!({ "name": priority }));
+        }
+        if let Some(ref parent) = self.parent {
+            fields.insert("parent".to_string(), json!({ "key": parent }));
+        }
+        
+        json!({ "fields": fields })
+    }
+}
+
+impl IssueUpdate {
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.update.is_empty()
+    }
+    
+    pub fn assign(mut self, user: &User) -> Self {
+        let assignee = match (&user.account_id, &user.name) {
+            (Some(account_id), _) => json!({ "accountId": account_id }),
+            (None, name) => json!({ "name": name }),
+        };
+        self.fields.insert("assignee".to_string(), assignee);
+        self
+    }
+    
+    pub fn add_labels(mut self, labels: &[String]) -> Self {
+        if !labels.is_empty() {
+            let operations: Vec<Value> = labels.iter().map(|label| json!({ "add": label })).collect();
+            self.update.insert("labels".to_string(), Value::Array(operations));
+        }
+        self
+    }
+    
+    pub fn add_fix_version(mut self, name: &str) -> Self {
+        self.update.insert("fixVersions".to_string(), json!([{ "add": { "name": name } }]));
+        self
+    }
+    
+    pub fn add_components(mut self, components: &[String]) -> Self {
+        if !components.is_empty() {
+            let operations: Vec<Value> = components
+                .iter()
+                .map(|name| json!({ "add": { "name": name } }))
+                .collect();
+            self.update.insert("components".to_string(), Value::Array(operations));
+        }
+        self
+    }
+}
+
+impl TransitionInput {
+    // comment는 fields가 아닌 update로 전달해야 합니다
+    pub fn set_value(&mut self, field_id: &str, value: Value) {
+        if field_id == "comment" {
+            let body = match value {
+                Value::String(text) => adf::from_markdown(&text),
+                other => other,
+            };
+            self.update.insert(field_id.to_string(), json!([{ "add": { "body": body } }]));
+        } else {
+            self.fields.insert(field_id.to_string(), value);
+        }
+    }
+    
+    pub fn has_value(&self, field_id: &str) -> bool {
+        self.fields.contains_key(field_id) || self.update.contains_key(field_id)
+    }
+    
+    pub fn missing_required<'a>(&self, transition: &'a Transition) -> Vec<(&'a String, &'a TransitionField)> {
+        let mut missing: Vec<(&String, &TransitionField)> = transition.fields
+            .iter()
+            .filter(|(id, field)| field.required && !field.has_default_value && !self.has_value(id))
+            .collect();
+        missing.sort_by(|a, b| a.0.cmp(b.0));
+        missing
+    }
+}
+
+impl JiraErrorResponse {
+    pub fn is_empty(&self) -> bool {
+        self.error_messages.is_empty() && self.errors.is_empty()
+    }
+    
+    pub fn format(&self) -> String {
+        let mut messages = self.error_messages.clone();
+        let mut field_errors: Vec<String> = self.errors
+            .iter()
+            .map(|(field, message)| format!("{}: {}", field, message))
+            .collect();
+        field_errors.sort();
+        messages.extend(field_errors);
+        messages.join(", ")
+    }
+}
+
+pub fn format_field_value(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Number(number) => number.to_string(),
+        Value::Bool(flag) => flag.to_string(),
+        Value::Array(values) => values
+            .iter()
+            .map(format_field_value)
+            .filter(|value| !value.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(object) if object.get("type").and_then(Value::as_str) == Some("doc") => {
+            adf::to_markdown(value).lines().collect::<Vec<_>>().join(" ")
+        }
+        Value::Object(object) => ["displayName", "name", "value", "key"]
+            .iter()
+            .find_map(|key| object.get(*key).and_then(|v| v.as_str()))
+            .map(str::to_string)
+            .unwrap_or_else(|| value.to_string()),
+    }
+}
+
+impl FromStr for SprintSelector {
+    type Err = std::convert::Infallible;
+    
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Ok(match value.trim().to_lowercase().as_str() {
+            "current" | "active" => SprintSelector::Current,
+            "next" | "future" => SprintSelector::Next,
+            _ => SprintSelector::Named(value.trim().to_string()),
+        })
+    }
+}
+
+impl std::fmt::Display for SprintSelector {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            SprintSelector::Current => write!(f, "current"),
+            SprintSelector::Next => write!(f, "next"),
+            SprintSelector::Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl Comment {
+    pub fn has_link(&self, href: &str) -> bool {
+        self.body
+            .as_ref()
+            .is_some_and(|body| adf::has_link(body, href))
+    }
+    
+    pub fn is_authored_by(&self, user: &User) -> bool {
+        self.author.as_ref().is_some_and(|author| user.is_same(author))
+    }
+}
+
+impl Sprint {
+    pub fn is_open(&self) -> bool {
+        self.state == "active" || self.state == "future"
+    }
+    
+    pub fn format_period(&self) -> String {
+        match (self.start_date, self.end_date) {
+            (Some(start), Some(end)) => format!("{} ~ {}", start.format("%Y-%m-%d"), end.format("%Y-%m-%d")),
+            _ => "기간 미정".to_string(),
+        }
+    }
+}
+
+impl SearchPage {
+    pub fn next_cursor(&self) -> Option<PageCursor> {
+        if self.is_last == Some(true) {
+            return None;
+        }
+        
+        if let Some(ref token) = self.next_page_token {
+            return Some(PageCursor::Token(token.clone()));
+        }
+        
+        let start_at = self.start_at?;
+        let next = start_at + self.issues.len();
+        (!self.issues.is_empty() && self.total.is_some_and(|total| next < total)).then_some(PageCursor::Offset(next))
+    }
+}
+
 impl Issue {
+    pub fn is_subtask(&self) -> bool {
+        self.fields.issuetype.subtask
+    }
+    
+    pub fn parent_key(&self) -> Option<&str> {
+        self.fields.parent.as_ref().map(|parent| parent.key.as_str())
+    }
+    
+    pub fn blockers(&self) -> Vec<&IssueRef> {
+        self.fields.issuelinks.iter().filter_map(|link| link.blocker()).collect()
+    }
+    
+    pub fn unresolved_blockers(&self) -> Vec<&IssueRef> {
+        self.blockers().into_iter().filter(|blocker| !blocker.is_done()).collect()
+    }
+    
+    pub fn blocked_issues(&self) -> Vec<&IssueRef> {
+        self.fields.issuelinks.iter().filter_map(|link| link.blocked()).collect()
+    }
+    
     pub fn format_summary(&self) -> String {
         format!("[{}] {}", self.key, self.fields.summary)
     }
@@ -121,6 +740,20 @@ impl Issue {
             .unwrap_or_else(|| "미할당".to_string())
     }
     
+    pub fn format_field(&self, field_id: &str) -> String {
+        let value = self.fields.extra.get(field_id).cloned().or_else(|| {
+            serde_json::to_value(&self.fields)
+                .ok()
+                .and_then(|fields| fields.get(field_id).cloned())
+        });
+        
+        value
+            .as_ref()
+            .map(format_field_value)
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| "없음".to_string())
+    }
+    
     pub fn format_priority(&self) -> String {
         self.fields.priority
             .as_ref()
@@ -131,8 +764,10 @@ impl Issue {
 
 #[derive(Debug, Clone, Display, EnumString)]
 pub enum TicketAction {
-    #[strum(to_string = "브랜치 생성 및 In Progress로 변경")]
+    #[strum(to_string = "브랜치 생성 및 작업 시작")]
     CreateBranch,
+    #[strum(to_string = "상세 정보 보기")]
+    ShowDetails,
     #[strum(to_string = "브라우저에서 열기")]
     OpenBrowser,
     #[strum(to_string = "취소")]
@@ -0,0 +1,143 @@
+use crate::error::{AppError, AppResult};
+use regex::Regex;
+
+#[derive(Debug, Clone)]
+pub struct TicketKeyParser {
+    project_key: String,
+    regex: Regex,
+}
+
+impl TicketKeyParser {
+    pub fn new(project_key: &str, pattern: Option<&str>) -> AppResult<Self> {
+        let pattern = match pattern {
+            Some(pattern) => pattern.to_string(),
+            None => format!(
+                r"(?i)(?:^|[^A-Za-z0-9])({}-\d+)(?:$|[^A-Za-z0-9])",
+                regex::escape(project_key)
+            ),
+        };
+        
+        let regex = Regex::new(&pattern)
+            .map_err(|e| AppError::config_error(format!("티켓 키 정규식이 올바르지 않습니다 ({}): {}", pattern, e)))?;
+        
+        Ok(TicketKeyParser { project_key: project_key.to_uppercase(), regex })
+    }
+    
+    // 캡처 그룹이 있으면 첫 번째 그룹을, 없으면 전체 매치를 티켓 키로 사용
+    fn key_match<'t>(&self, captures: &regex::Captures<'t>) -> Option<regex::Match<'t>> {
+        captures.get(1).or_else(|| captures.get(0))
+    }
+    
+    // 사용자 정의 패턴이 번호만 캡처하면 프로젝트 키를 붙여 완전한 티켓 키로 만듭니다
+    fn normalize(&self, matched: &str) -> String {
+        if !matched.is_empty() && matched.chars().all(|c| c.is_ascii_digit()) {
+            format!("{}-{}", self.project_key, matched)
+        } else {
+            matched.to_uppercase()
+        }
+    }
+    
+    fn key_from(&self, captures: &regex::Captures) -> Option<String> {
+        self.key_match(captures).map(|m| self.normalize(m.as_str()))
+    }
+    
+    pub fn extract(&self, text: &str) -> Option<String> {
+        self.regex
+            .captures(text)
+            .and_then(|captures| self.key_from(&captures))
+    }
+    
+    pub fn extract_all(&self, text: &str) -> Vec<String> {
+        let mut keys: Vec<String> = Vec::new();
+        
+        let mut start = 0;
+        
+        // 앞뒤 경계 문자를 매치에 포함하므로 "PROJ-1-PROJ-2" 처럼 붙어 있는 키도 찾도록
+        // 다음 검색은 전체 매치가 아닌 티켓 키가 끝난 위치에서 시작합니다
+        while start <= text.len() {
+            let Some(captures) = self.regex.captures_at(text, start) else {
+                break;
+            };
+            let Some(matched) = self.key_match(&captures) else {
+                break;
+            };
+            
+            let key = self.normalize(matched.as_str());
+            if !key.is_empty() && !keys.contains(&key) {
+                keys.push(key);
+            }
+            
+            start = if matched.end() > start {
+                matched.end()
+            } else {
+                text[start..].chars().next().map_or(text.len() + 1, |c| start + c.len_utf8())
+            };
+        }
+        
+        keys
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn parser() -> TicketKeyParser {
+        TicketKeyParser::new("PROJ", None).unwrap()
+    }
+    
+    #[test]
+    fn extracts_key_from_prefixed_branches() {
+        assert_eq!(parser().extract("PROJ-12"), Some("PROJ-12".to_string()));
+        assert_eq!(parser().extract("feature/PROJ-12-login-form"), Some("PROJ-12".to_string()));
+        assert_eq!(parser().extract("fix/PROJ-7"), Some("PROJ-7".to_string()));
+        assert_eq!(parser().extract("[PROJ-3] 커밋 메시지"), Some("PROJ-3".to_string()));
+    }
+    
+    #[test]
+    fn normalizes_lowercase_keys() {
+        assert_eq!(parser().extract("feature/proj-12-login"), Some("PROJ-12".to_string()));
+    }
+    
+    #[test]
+    fn requires_boundaries_on_both_sides() {
+        assert_eq!(parser().extract("XPROJ-12"), None);
+        assert_eq!(parser().extract("feature/PROJ-12abc"), None);
+        assert_eq!(parser().extract("PROJ-"), None);
+        assert_eq!(parser().extract("feature/PROJ-123"), Some("PROJ-123".to_string()));
+    }
+    
+    #[test]
+    fn custom_pattern_uses_capture_group_when_present() {
+        let parser = TicketKeyParser::new("PROJ", Some(r"ticket-(\d+)")).unwrap();
+        assert_eq!(parser.extract("feature/ticket-42"), Some("PROJ-42".to_string()));
+        assert_eq!(parser.extract_all("ticket-1, ticket-2"), vec!["PROJ-1".to_string(), "PROJ-2".to_string()]);
+    }
+    
+    #[test]
+    fn custom_pattern_without_group_uses_whole_match() {
+        let parser = TicketKeyParser::new("PROJ", Some(r"[A-Z]+-\d+")).unwrap();
+        assert_eq!(parser.extract("feature/ABC-9-test"), Some("ABC-9".to_string()));
+    }
+    
+    #[test]
+    fn invalid_custom_pattern_is_config_error() {
+        assert!(TicketKeyParser::new("PROJ", Some("(")).is_err());
+    }
+    
+    #[test]
+    fn extract_all_deduplicates_and_keeps_order() {
+        assert_eq!(
+            parser().extract_all("PROJ-2 fix, proj-1 and PROJ-2 again (PROJ-3)"),
+            vec!["PROJ-2".to_string(), "PROJ-1".to_string(), "PROJ-3".to_string()]
+        );
+    }
+    
+    #[test]
+    fn extract_all_finds_adjacent_keys() {
+        assert_eq!(
+            parser().extract_all("PROJ-1-PROJ-2"),
+            vec!["PROJ-1".to_string(), "PROJ-2".to_string()]
+        );
+    }
+}
@@ -0,0 +1,179 @@
+use super::models::{Status, Transition, TransitionInput};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use strum::Display;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Display)]
+pub enum WorkflowStage {
+    #[strum(to_string = "In Progress")]
+    Start,
+    #[strum(to_string = "In Review")]
+    Review,
+    #[strum(to_string = "Done")]
+    Done,
+}
+
+impl WorkflowStage {
+    // 리뷰 단계는 대응되는 StatusCategory가 없으므로 매핑이 있을 때만 사용
+    pub fn category_key(&self) -> Option<&'static str> {
+        match self {
+            WorkflowStage::Start => Some("indeterminate"),
+            WorkflowStage::Review => None,
+            WorkflowStage::Done => Some("done"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct WorkflowConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub start: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub review: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub done: Vec<String>,
+    // 전환명 또는 대상 상태명("*"는 모든 전환) → 필드 ID → 기본값
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub fields: HashMap<String, HashMap<String, Value>>,
+}
+
+impl WorkflowConfig {
+    pub fn names(&self, stage: WorkflowStage) -> &[String] {
+        match stage {
+            WorkflowStage::Start => &self.start,
+            WorkflowStage::Review => &self.review,
+            WorkflowStage::Done => &self.done,
+        }
+    }
+    
+    pub fn is_configured(&self, stage: WorkflowStage) -> bool {
+        !self.names(stage).is_empty() || stage.category_key().is_some()
+    }
+    
+    pub fn label(&self, stage: WorkflowStage) -> String {
+        self.names(stage)
+            .first()
+            .cloned()
+            .unwrap_or_else(|| stage.to_string())
+    }
+    
+    fn matches_name(&self, stage: WorkflowStage, name: &str) -> bool {
+        self.names(stage)
+            .iter()
+            .any(|candidate| candidate.to_lowercase() == name.to_lowercase())
+    }
+    
+    fn matches_category(stage: WorkflowStage, status: &Status) -> bool {
+        match (stage.category_key(), &status.status_category) {
+            (Some(key), Some(category)) => category.key == key,
+            _ => false,
+        }
+    }
+    
+    pub fn is_in_stage(&self, status: &Status, stage: WorkflowStage) -> bool {
+        if self.names(stage).is_empty() {
+            Self::matches_category(stage, status)
+        } else {
+            self.matches_name(stage, &status.name)
+        }
+    }
+    
+    pub fn default_input(&self, transition: &Transition) -> TransitionInput {
+        let mut input = TransitionInput::default();
+        
+        let mut matching: Vec<(&String, &HashMap<String, Value>)> = self.fields
+            .iter()
+            .filter(|(name, _)| {
+                name.as_str() == "*"
+                    || name.to_lowercase() == transition.name.to_lowercase()
+                    || name.to_lowercase() == transition.to.name.to_lowercase()
+            })
+            .collect();
+        // 구체적인 이름의 기본값이 "*"보다 우선합니다
+        matching.sort_by_key(|(name, _)| name.as_str() != "*");
+        
+        // 전환 화면에 없는 필드를 보내면 Jira가 요청을 거부하므로 화면에 있는 필드만 채웁니다
+        for (_, defaults) in matching {
+            for (field_id, value) in defaults {
+                if transition.fields.contains_key(field_id) {
+                    input.set_value(field_id, value.clone());
+                }
+            }
+        }
+        
+        input
+    }
+    
+    pub fn matches_transition(&self, transition: &Transition, stage: WorkflowStage) -> bool {
+        if self.names(stage).is_empty() {
+            Self::matches_category(stage, &transition.to)
+        } else {
+            self.matches_name(stage, &transition.name) || self.matches_name(stage, &transition.to.name)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum TransitionTarget {
+    Stage(WorkflowStage),
+    Status(String),
+    // 목록에서 직접 고른 전환 (이름이 같은 전환이 여러 개일 수 있어 id로 구분)
+    Transition(Transition),
+}
+
+impl TransitionTarget {
+    pub fn matches(&self, workflow: &WorkflowConfig, transition: &Transition) -> bool {
+        match self {
+            TransitionTarget::Stage(stage) => workflow.matches_transition(transition, *stage),
+            TransitionTarget::Status(name) => {
+                let name = name.to_lowercase();
+                transition.name.to_lowercase() == name || transition.to.name.to_lowercase() == name
+            }
+            TransitionTarget::Transition(selected) => transition.id == selected.id,
+        }
+    }
+    
+    // 직접 고른 전환은 현재 상태에서 바로 실행할 수 있어야 하므로 경로를 찾지 않습니다
+    pub fn is_direct(&self) -> bool {
+        matches!(self, TransitionTarget::Transition(_))
+    }
+    
+    pub fn label(&self, workflow: &WorkflowConfig) -> String {
+        match self {
+            TransitionTarget::Stage(stage) => workflow.label(*stage),
+            TransitionTarget::Status(name) => name.clone(),
+            TransitionTarget::Transition(selected) if selected.name == selected.to.name => selected.name.clone(),
+            TransitionTarget::Transition(selected) => format!("{} → {}", selected.name, selected.to.name),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TransitionPath {
+    pub from: Status,
+    pub steps: Vec<Transition>,
+}
+
+impl TransitionPath {
+    pub fn is_multi_step(&self) -> bool {
+        self.steps.len() > 1
+    }
+    
+    pub fn target(&self) -> Option<&Status> {
+        self.steps.last().map(|step| &step.to)
+    }
+    
+    pub fn default_inputs(&self, workflow: &WorkflowConfig) -> Vec<TransitionInput> {
+        self.steps
+            .iter()
+            .map(|step| workflow.default_input(step))
+            .collect()
+    }
+    
+    pub fn preview(&self) -> String {
+        let mut statuses = vec![self.from.name.clone()];
+        statuses.extend(self.steps.iter().map(|step| step.to.name.clone()));
+        statuses.join(" → ")
+    }
+}
@@ -0,0 +1,123 @@
+// Jira 기본 작업 시간 설정 기준: 1d = 8h, 1w = 5d
+const MINUTE: u64 = 60;
+const HOUR: u64 = 60 * MINUTE;
+const DAY: u64 = 8 * HOUR;
+const WEEK: u64 = 5 * DAY;
+// 타이머를 끄지 않고 방치한 경우 등 잘못된 값이 기록되지 않도록 한 번에 1주까지만 허용
+const MAX_DURATION: u64 = WEEK;
+
+// "1h 30m", "2d", "45m", "1.5h" 형식의 작업 시간을 초 단위로 변환합니다 (단위가 없으면 분)
+pub fn parse_duration(text: &str) -> Option<u64> {
+    let text = text.trim().to_lowercase();
+    if text.is_empty() {
+        return None;
+    }
+    
+    let total = match text.parse::<f64>() {
+        Ok(minutes) => minutes * MINUTE as f64,
+        Err(_) => parse_units(&text)?,
+    };
+    
+    // "inf", "nan" 처럼 f64로 파싱되는 값이나 반올림하면 0초가 되는 값은 거부합니다
+    if !total.is_finite() || total.round() < 1.0 || total.round() > MAX_DURATION as f64 {
+        return None;
+    }
+    
+    Some(total.round() as u64)
+}
+
+fn parse_units(text: &str) -> Option<f64> {
+    let mut total = 0.0;
+    let mut number = String::new();
+    
+    for c in text.chars() {
+        match c {
+            '0'..='9' | '.' => number.push(c),
+            'w' | 'd' | 'h' | 'm' | 's' => {
+                let value: f64 = number.parse().ok()?;
+                let unit = match c {
+                    'w' => WEEK,
+                    'd' => DAY,
+                    'h' => HOUR,
+                    'm' => MINUTE,
+                    _ => 1,
+                };
+                total += value * unit as f64;
+                number.clear();
+            }
+            c if c.is_whitespace() => {}
+            _ => return None,
+        }
+    }
+    
+    if !number.is_empty() {
+        return None;
+    }
+    
+    Some(total)
+}
+
+// Jira에 맞춰 분 단위로 올림하고 최소 1분으로 표시합니다 (예: "1d 2h 5m")
+pub fn format_duration(seconds: u64) -> String {
+    let minutes = seconds.div_ceil(MINUTE).max(1);
+    let mut remaining = minutes * MINUTE;
+    let mut parts = Vec::new();
+    
+    for (unit, label) in [(WEEK, "w"), (DAY, "d"), (HOUR, "h"), (MINUTE, "m")] {
+        if remaining >= unit {
+            parts.push(format!("{}{}", remaining / unit, label));
+            remaining %= unit;
+        }
+    }
+    
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_units_and_plain_minutes() {
+        assert_eq!(parse_duration("1h 30m"), Some(HOUR + 30 * MINUTE));
+        assert_eq!(parse_duration("2d"), Some(2 * DAY));
+        assert_eq!(parse_duration("1.5h"), Some(90 * MINUTE));
+        assert_eq!(parse_duration("45"), Some(45 * MINUTE));
+        assert_eq!(parse_duration(" 1W "), Some(WEEK));
+    }
+    
+    #[test]
+    fn rejects_malformed_and_empty_values() {
+        assert_eq!(parse_duration(""), None);
+        assert_eq!(parse_duration("1x"), None);
+        assert_eq!(parse_duration("h"), None);
+        assert_eq!(parse_duration("1h 30"), None);
+        assert_eq!(parse_duration("0"), None);
+        assert_eq!(parse_duration("-5"), None);
+        assert_eq!(parse_duration("0.001s"), None);
+    }
+    
+    #[test]
+    fn rejects_non_finite_values() {
+        assert_eq!(parse_duration("inf"), None);
+        assert_eq!(parse_duration("infinity"), None);
+        assert_eq!(parse_duration("NaN"), None);
+        assert_eq!(parse_duration("1e400"), None);
+        assert_eq!(parse_duration("1e400h"), None);
+    }
+    
+    #[test]
+    fn rejects_values_above_maximum() {
+        assert_eq!(parse_duration("1w 1m"), None);
+        assert_eq!(parse_duration("6d"), None);
+        assert_eq!(parse_duration("100000"), None);
+        assert_eq!(parse_duration("5d"), Some(MAX_DURATION));
+    }
+    
+    #[test]
+    fn formats_rounded_up_to_minutes() {
+        assert_eq!(format_duration(0), "1m");
+        assert_eq!(format_duration(61), "2m");
+        assert_eq!(format_duration(DAY + 2 * HOUR + 5 * MINUTE), "1d 2h 5m");
+    }
+}
@@ -1,10 +1,15 @@
 pub mod cli;
+pub mod code_host;
 pub mod config;
 pub mod context;
 pub mod error;
 pub mod git;
+pub mod issue_tracker;
 pub mod github;
+pub mod gitlab;
+pub mod bitbucket;
 pub mod jira;
+pub mod timer;
 pub mod utils;
 
 pub use config::Config;
@@ -0,0 +1,84 @@
+use crate::{AppError, AppErrorType, AppResult, Config};
+use crate::error::AppErrorExt;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TicketTimer {
+    pub ticket: String,
+    #[serde(rename = "startedAt")]
+    pub started_at: DateTime<Utc>,
+}
+
+impl TicketTimer {
+    pub fn elapsed_seconds(&self) -> u64 {
+        (Utc::now() - self.started_at).num_seconds().max(0) as u64
+    }
+}
+
+// 티켓별 타이머를 <프로젝트 루트>/.jgf/timers/<티켓>.json 에 저장합니다
+pub struct TimerStore {
+    dir: PathBuf,
+}
+
+impl TimerStore {
+    pub fn new(config: &Config) -> Self {
+        Self { dir: config.state_dir().join("timers") }
+    }
+    
+    fn path(&self, ticket: &str) -> PathBuf {
+        self.dir.join(format!("{}.json", ticket))
+    }
+    
+    pub fn get(&self, ticket: &str) -> AppResult<Option<TicketTimer>> {
+        let path = self.path(ticket);
+        if !path.exists() {
+            return Ok(None);
+        }
+        
+        let content = fs::read_to_string(&path)
+            .with_app_type(AppErrorType::IoError(format!("타이머 파일 읽기 실패: {}", path.display())))?;
+        let timer = serde_json::from_str(&content)
+            .map_err(|e| AppError::validation_error(format!("타이머 파일 형식 오류 ({}): {}", path.display(), e)))?;
+        
+        Ok(Some(timer))
+    }
+    
+    pub fn start(&self, ticket: &str) -> AppResult<TicketTimer> {
+        let timer = TicketTimer {
+            ticket: ticket.to_string(),
+            started_at: Utc::now(),
+        };
+        
+        fs::create_dir_all(&self.dir)
+            .with_app_type(AppErrorType::IoError(format!("디렉토리 생성 실패: {}", self.dir.display())))?;
+        
+        // 로컬 상태가 Git에 커밋되지 않도록 .jgf 디렉토리 전체를 무시합니다
+        if let Some(state_dir) = self.dir.parent() {
+            let ignore_file = state_dir.join(".gitignore");
+            if !ignore_file.exists() {
+                fs::write(&ignore_file, "*\n")
+                    .with_app_type(AppErrorType::IoError(format!("파일 쓰기 실패: {}", ignore_file.display())))?;
+            }
+        }
+        
+        let content = serde_json::to_string_pretty(&timer)
+            .map_err(|e| AppError::validation_error(format!("타이머 직렬화 실패: {}", e)))?;
+        fs::write(self.path(ticket), content)
+            .with_app_type(AppErrorType::IoError("타이머 파일 쓰기 실패".to_string()))?;
+        
+        Ok(timer)
+    }
+    
+    pub fn clear(&self, ticket: &str) -> AppResult<()> {
+        let path = self.path(ticket);
+        if path.exists() {
+            fs::remove_file(&path)
+                .with_app_type(AppErrorType::IoError(format!("타이머 파일 삭제 실패: {}", path.display())))?;
+        }
+        
+        Ok(())
+    }
+}
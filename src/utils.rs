@@ -1,4 +1,6 @@
+use crate::config::Config;
 use crate::error::{AppError, AppErrorExt, AppResult};
+use crate::issue_tracker::IssueTrackerKind;
 use colored::Colorize;
 use console::{style, Emoji};
 use indicatif::{ProgressBar, ProgressStyle};
@@ -72,6 +74,22 @@ pub fn sparkle_message(message: &str) {
     println!("{}{}", SPARKLE, message.green().bold());
 }
 
+pub fn link_message(message: &str) {
+    println!("{}{}", LINK, message);
+}
+
+pub fn package_message(message: &str) {
+    println!("{}{}", PACKAGE, message);
+}
+
+pub fn sync_message(message: &str) {
+    println!("{}{}", SYNC, message.bold());
+}
+
+pub fn trash_message(message: &str) {
+    println!("{}{}", TRASH, message);
+}
+
 pub fn prompt_confirmation(message: &str) -> AppResult<bool> {
     let answer = inquire::Confirm::new(message)
         .with_default(false)
@@ -102,11 +120,19 @@ pub fn prompt_select<T: std::fmt::Display>(
         .map_err(|e| AppError::validation_error(format!("선택 오류: {}", e)))
 }
 
-pub fn format_ticket_key(project: &str, number: &str) -> String {
-    if number.to_uppercase().starts_with(project) {
-        number.to_uppercase()
+pub fn format_ticket_key(config: &Config, ticket: &str) -> String {
+    let ticket = ticket.trim();
+    // GitHub 이슈는 #12 형식으로도 입력할 수 있습니다
+    let ticket = match config.issue_tracker {
+        IssueTrackerKind::GitHub => ticket.trim_start_matches('#'),
+        IssueTrackerKind::Jira => ticket,
+    };
+    
+    // 번호만 입력하면 티켓 키 접두사를 붙입니다
+    if !ticket.is_empty() && ticket.chars().all(|c| c.is_ascii_digit()) {
+        format!("{}-{}", config.ticket_prefix, ticket)
     } else {
-        format!("{}-{}", project, number)
+        ticket.to_uppercase()
     }
 }
 
@@ -118,4 +144,113 @@ pub fn validate_ticket_key(key: &str) -> bool {
     
     parts[0].chars().all(|c| c.is_ascii_uppercase()) &&
     parts[1].chars().all(|c| c.is_ascii_digit())
-}
\ No newline at end of file
+}
+
+pub fn slugify(text: &str, max_length: usize) -> String {
+    let mut slug = String::new();
+    
+    for c in text.trim().chars().flat_map(|c| c.to_lowercase()) {
+        if c.is_alphanumeric() {
+            slug.push(c);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+    }
+    
+    let slug: String = slug.chars().take(max_length).collect();
+    slug.trim_matches('-').to_string()
+}
+
+// git check-ref-format 규칙에 맞지 않는 문자와 구간을 정리
+pub fn sanitize_branch_name(name: &str) -> String {
+    let replaced: String = name
+        .chars()
+        .map(|c| match c {
+            ' ' | '~' | '^' | ':' | '?' | '*' | '[' | '\\' => '-',
+            c if c.is_control() => '-',
+            c => c,
+        })
+        .collect();
+    
+    let mut replaced = replaced.replace("@{", "@");
+    while replaced.contains("..") {
+        replaced = replaced.replace("..", ".");
+    }
+    
+    replaced
+        .split('/')
+        .map(|component| {
+            let mut component = component.to_string();
+            while component.contains("--") {
+                component = component.replace("--", "-");
+            }
+            // "a.lock." 처럼 정리 후 다시 규칙에 어긋날 수 있어 더 이상 바뀌지 않을 때까지 반복
+            loop {
+                let trimmed = component.trim_matches(|c| c == '-' || c == '.');
+                let trimmed = trimmed.strip_suffix(".lock").unwrap_or(trimmed);
+                if trimmed.len() == component.len() {
+                    break component;
+                }
+                component = trimmed.to_string();
+            }
+        })
+        .filter(|component| !component.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn slugify_replaces_separators_and_trims_dashes() {
+        assert_eq!(slugify("  Fix login: bug!  ", 40), "fix-login-bug");
+        assert_eq!(slugify("--already--dashed--", 40), "already-dashed");
+        assert_eq!(slugify("!!!", 40), "");
+    }
+    
+    #[test]
+    fn slugify_keeps_non_ascii_letters() {
+        assert_eq!(slugify("로그인 오류 수정", 40), "로그인-오류-수정");
+        assert_eq!(slugify("Ünïcode Tëst", 40), "ünïcode-tëst");
+    }
+    
+    #[test]
+    fn slugify_truncates_at_length_cap_by_characters() {
+        assert_eq!(slugify("abcdef ghij", 6), "abcdef");
+        // 잘린 위치가 구분자이면 끝의 '-'를 제거합니다
+        assert_eq!(slugify("abcde fghij", 6), "abcde");
+        assert_eq!(slugify("가나다라마바사", 3), "가나다");
+        assert_eq!(slugify("a".repeat(100).as_str(), 40).chars().count(), 40);
+    }
+    
+    #[test]
+    fn sanitize_collapses_double_dots_and_reflog_syntax() {
+        assert_eq!(sanitize_branch_name("feature/a..b"), "feature/a.b");
+        assert_eq!(sanitize_branch_name("feature/a....b"), "feature/a.b");
+        assert_eq!(sanitize_branch_name("fix/head@{1}"), "fix/head@1}");
+    }
+    
+    #[test]
+    fn sanitize_strips_lock_suffix_and_trailing_dots() {
+        assert_eq!(sanitize_branch_name("feature/config.lock"), "feature/config");
+        assert_eq!(sanitize_branch_name("feature/config.lock."), "feature/config");
+        assert_eq!(sanitize_branch_name("feature/a.lock.lock"), "feature/a");
+        assert_eq!(sanitize_branch_name("feature/name."), "feature/name");
+        assert_eq!(sanitize_branch_name(".hidden/name"), "hidden/name");
+    }
+    
+    #[test]
+    fn sanitize_removes_empty_components_and_slashes() {
+        assert_eq!(sanitize_branch_name("/feature//PROJ-1/"), "feature/PROJ-1");
+        assert_eq!(sanitize_branch_name("feature/-/PROJ-1"), "feature/PROJ-1");
+    }
+    
+    #[test]
+    fn sanitize_replaces_invalid_characters() {
+        assert_eq!(sanitize_branch_name("feature/PROJ-1 a~b^c:d?e*f[g\\h"), "feature/PROJ-1-a-b-c-d-e-f-g-h");
+        assert_eq!(sanitize_branch_name("feature/a\tb"), "feature/a-b");
+        assert_eq!(sanitize_branch_name("feature/로그인-수정"), "feature/로그인-수정");
+    }
+}
@@ -0,0 +1,71 @@
+// Jira Data Center 8.x(/rest/api/2)에서 기록한 응답으로 Server 모드 모델을 검증합니다
+use jgf::jira::{adf, Issue, IssueUpdate, JiraFlavor, PageCursor, SearchPage, User};
+use serde_json::json;
+
+const ISSUE: &str = include_str!("fixtures/jira_server/issue.json");
+const SEARCH_PAGE: &str = include_str!("fixtures/jira_server/search_page.json");
+const MYSELF: &str = include_str!("fixtures/jira_server/myself.json");
+
+#[test]
+fn parses_issue_with_plain_text_description() {
+    let issue: Issue = serde_json::from_str(ISSUE).unwrap();
+    
+    assert_eq!(issue.key, "EM-42");
+    assert_eq!(issue.fields.status.name, "To Do");
+    assert_eq!(issue.fields.created.to_rfc3339(), "2024-03-04T01:15:30+00:00");
+    assert_eq!(issue.format_assignee(), "김개발");
+    
+    let description = issue.fields.description.as_ref().unwrap();
+    assert!(adf::to_terminal(description).starts_with("h2. 배경\r\n세션이 만료되면"));
+    assert_eq!(adf::to_markdown(description), description.as_str().unwrap());
+}
+
+#[test]
+fn finds_unresolved_blockers_from_issue_links() {
+    let issue: Issue = serde_json::from_str(ISSUE).unwrap();
+    
+    let blockers: Vec<&str> = issue.blockers().iter().map(|blocker| blocker.key.as_str()).collect();
+    assert_eq!(blockers, ["EM-40", "EM-31"]);
+    
+    let unresolved: Vec<&str> = issue.unresolved_blockers().iter().map(|blocker| blocker.key.as_str()).collect();
+    assert_eq!(unresolved, ["EM-40"]);
+    assert!(issue.blocked_issues().is_empty());
+}
+
+#[test]
+fn paginates_search_with_start_at() {
+    let page: SearchPage = serde_json::from_str(SEARCH_PAGE).unwrap();
+    
+    assert_eq!(page.issues.len(), 2);
+    assert_eq!(page.next_page_token, None);
+    assert_eq!(page.next_cursor(), Some(PageCursor::Offset(2)));
+    
+    let last_page = SearchPage { start_at: Some(2), total: Some(4), ..page };
+    assert_eq!(last_page.next_cursor(), None);
+}
+
+#[test]
+fn assigns_by_user_name() {
+    let myself: User = serde_json::from_str(MYSELF).unwrap();
+    let issue: Issue = serde_json::from_str(ISSUE).unwrap();
+    
+    assert_eq!(myself.account_id, None);
+    assert!(myself.can_be_assigned());
+    assert!(myself.is_same(issue.fields.assignee.as_ref().unwrap()));
+    assert!(!myself.is_same(issue.fields.reporter.as_ref().unwrap()));
+    
+    let update = serde_json::to_value(IssueUpdate::default().assign(&myself)).unwrap();
+    assert_eq!(update["fields"]["assignee"], json!({ "name": "kim.dev" }));
+}
+
+#[test]
+fn parses_flavor_names() {
+    assert_eq!("server".parse::<JiraFlavor>().unwrap(), JiraFlavor::Server);
+    assert_eq!("DataCenter".parse::<JiraFlavor>().unwrap(), JiraFlavor::Server);
+    assert_eq!("cloud".parse::<JiraFlavor>().unwrap(), JiraFlavor::Cloud);
+    assert!("onprem".parse::<JiraFlavor>().is_err());
+    
+    let flavor: JiraFlavor = serde_json::from_value(json!("datacenter")).unwrap();
+    assert_eq!(flavor, JiraFlavor::Server);
+    assert_eq!(flavor.api_version(), 2);
+}